@@ -0,0 +1,64 @@
+//! Measures the cost of styling one frame of widgets.
+//!
+//! Every Modern style function looks up the theme colors, so a frame of a
+//! few hundred widgets calls `get_theme_colors` a few hundred times. Run
+//! with `cargo bench --bench theme_colors`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use iced::widget::{button, checkbox, pick_list, text_input};
+use iced::Theme;
+use iced_modern_theme::{get_theme_colors, Modern};
+
+/// Widgets styled per frame, split evenly over the six styles below
+const WIDGETS_PER_FRAME: usize = 600;
+
+const FRAMES: u32 = 2_000;
+
+fn style_frame(theme: &Theme) {
+    let primary = Modern::primary_button();
+    let secondary = Modern::secondary_button();
+    let input = Modern::text_input();
+    let card = Modern::card_container();
+    let check = Modern::checkbox();
+    let pick = Modern::pick_list();
+
+    for _ in 0..WIDGETS_PER_FRAME / 6 {
+        black_box(primary(theme, button::Status::Active));
+        black_box(secondary(theme, button::Status::Hovered));
+        black_box(input(theme, text_input::Status::Active));
+        black_box(card(theme));
+        black_box(check(theme, checkbox::Status::Active { is_checked: true }));
+        black_box(pick(theme, pick_list::Status::Active));
+    }
+}
+
+/// The color lookups alone, one per widget
+fn color_frame(theme: &Theme) {
+    for _ in 0..WIDGETS_PER_FRAME {
+        black_box(get_theme_colors(black_box(theme)));
+    }
+}
+
+fn bench(label: &str, theme: &Theme, frame: fn(&Theme)) {
+    // Warm up caches and the allocator
+    for _ in 0..FRAMES / 10 {
+        frame(theme);
+    }
+
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        frame(black_box(theme));
+    }
+    let per_frame = start.elapsed() / FRAMES;
+
+    println!("{label:<14} {:>8.1} µs/frame", per_frame.as_secs_f64() * 1e6);
+}
+
+fn main() {
+    for (label, theme) in [("light", Modern::light_theme()), ("dark", Modern::dark_theme())] {
+        bench(&format!("{label} styles"), &theme, style_frame);
+        bench(&format!("{label} colors"), &theme, color_frame);
+    }
+}
@@ -1,7 +1,6 @@
 use iced::{Element, Task, Theme, Length, Border, Color, Shadow, Background};
 use iced::widget::{
-    text, button, container, text_input, column, row, vertical_space, 
-    horizontal_space, radio, checkbox, pick_list, scrollable, combo_box
+    text, button, container, text_input, column, row, space, radio, checkbox, pick_list, scrollable, combo_box
 };
 
 use iced_modern_theme::Modern;
@@ -144,7 +143,7 @@ impl ModernThemeDemo {
         self.theme.clone()
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         // Header section
         let header = container(
             text("Modern-style Theme & Colors Showcase")
@@ -157,10 +156,10 @@ impl ModernThemeDemo {
         let theme_section = container(
             row![
                 text("Theme:").size(16),
-                horizontal_space().width(10),
+                space().width(10),
                 radio("Light", ThemeChoice::Light, Some(self.theme_choice), Message::ThemeChanged)
                     .style(Modern::radio()),
-                horizontal_space().width(10),
+                space().width(10),
                 radio("Dark", ThemeChoice::Dark, Some(self.theme_choice), Message::ThemeChanged)
                     .style(Modern::radio()),
             ]
@@ -172,11 +171,11 @@ impl ModernThemeDemo {
         let color_showcase = container(
             column![
                 text("System Colors").size(20),
-                vertical_space().height(10),
+                space().height(10),
                 
                 // Primary colors row
                 text("Primary Colors").size(16),
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     color_tile("Red", colors::system::RED, colors::system::RED_DARK, &self.theme),
@@ -184,7 +183,7 @@ impl ModernThemeDemo {
                     color_tile("Yellow", colors::system::YELLOW, colors::system::YELLOW_DARK, &self.theme),
                     color_tile("Green", colors::system::GREEN, colors::system::GREEN_DARK, &self.theme),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     color_tile("Mint", colors::system::MINT, colors::system::MINT_DARK, &self.theme),
@@ -192,7 +191,7 @@ impl ModernThemeDemo {
                     color_tile("Cyan", colors::system::CYAN, colors::system::CYAN_DARK, &self.theme),
                     color_tile("Blue", colors::system::BLUE, colors::system::BLUE_DARK, &self.theme),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     color_tile("Indigo", colors::system::INDIGO, colors::system::INDIGO_DARK, &self.theme),
@@ -202,16 +201,16 @@ impl ModernThemeDemo {
                 ],
                 
                 // Gray scale colors
-                vertical_space().height(10),
+                space().height(10),
                 text("Gray Scale").size(16),
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     color_tile("Gray 1", colors::gray::GRAY1_LIGHT, colors::gray::GRAY1_DARK, &self.theme),
                     color_tile("Gray 2", colors::gray::GRAY2_LIGHT, colors::gray::GRAY2_DARK, &self.theme),
                     color_tile("Gray 3", colors::gray::GRAY3_LIGHT, colors::gray::GRAY3_DARK, &self.theme),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     color_tile("Gray 4", colors::gray::GRAY4_LIGHT, colors::gray::GRAY4_DARK, &self.theme),
@@ -228,7 +227,7 @@ impl ModernThemeDemo {
         let button_showcase = container(
             column![
                 text("Button Styles").size(20),
-                vertical_space().height(10),
+                space().height(10),
                 
                 // Primary row
                 row![
@@ -246,7 +245,7 @@ impl ModernThemeDemo {
                     )
                     .width(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Success & warning row
                 row![
@@ -264,7 +263,7 @@ impl ModernThemeDemo {
                     )
                     .width(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Danger & System row
                 row![
@@ -282,7 +281,7 @@ impl ModernThemeDemo {
                     )
                     .width(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Text buttons row
                 row![
@@ -302,9 +301,9 @@ impl ModernThemeDemo {
                 ],
                 
                 // Disabled buttons
-                vertical_space().height(10),
+                space().height(10),
                 text("Disabled Buttons").size(16),
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     container(
@@ -319,7 +318,7 @@ impl ModernThemeDemo {
                     )
                     .width(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
 
                 // Success & warning row
                 row![
@@ -335,7 +334,7 @@ impl ModernThemeDemo {
                     )
                     .width(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Danger & System row
                 row![
@@ -351,7 +350,7 @@ impl ModernThemeDemo {
                     )
                     .width(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Text buttons row
                 row![
@@ -377,7 +376,7 @@ impl ModernThemeDemo {
         let extended_button_showcase = container(
             column![
                 text("Extended Button Styles").size(20),
-                vertical_space().height(10),
+                space().height(10),
                 
                 row![
                     container(
@@ -394,7 +393,7 @@ impl ModernThemeDemo {
                     )
                     .width(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     container(
@@ -421,7 +420,7 @@ impl ModernThemeDemo {
         let button_variants_showcase = container(
             column![
                 text("Button Variants").size(20),
-                vertical_space().height(10),
+                space().height(10),
                 
                 // Plain, Gray, Tinted, Filled heading
                 row![
@@ -441,16 +440,16 @@ impl ModernThemeDemo {
                         .width(Length::Fill)
                         .center_x(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Small buttons row
                 text("Small").size(16).style(Modern::secondary_text()),
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     // Plain small button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::plain_button())
                             .on_press(Message::ButtonClicked("Small Plain"))
                             .padding(5)
@@ -460,7 +459,7 @@ impl ModernThemeDemo {
                     
                     // Gray small button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::gray_button())
                             .on_press(Message::ButtonClicked("Small Gray"))
                             .padding(5)
@@ -470,7 +469,7 @@ impl ModernThemeDemo {
                     
                     // Tinted small button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::blue_tinted_button())
                             .on_press(Message::ButtonClicked("Small Tinted"))
                             .padding(5)
@@ -480,7 +479,7 @@ impl ModernThemeDemo {
                     
                     // Filled small button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::primary_button())
                             .on_press(Message::ButtonClicked("Small Filled"))
                             .padding(5)
@@ -488,16 +487,16 @@ impl ModernThemeDemo {
                     .width(Length::Fill)
                     .center_x(Length::Fill),
                 ],
-                vertical_space().height(15),
+                space().height(15),
                 
                 // Medium buttons row
                 text("Medium").size(16).style(Modern::secondary_text()),
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     // Plain medium button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::plain_button())
                             .on_press(Message::ButtonClicked("Medium Plain"))
                             .padding(8)
@@ -507,7 +506,7 @@ impl ModernThemeDemo {
                     
                     // Gray medium button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::gray_button())
                             .on_press(Message::ButtonClicked("Medium Gray"))
                             .padding(8)
@@ -517,7 +516,7 @@ impl ModernThemeDemo {
                     
                     // Tinted medium button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::blue_tinted_button())
                             .on_press(Message::ButtonClicked("Medium Tinted"))
                             .padding(8)
@@ -527,7 +526,7 @@ impl ModernThemeDemo {
                     
                     // Filled medium button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::primary_button())
                             .on_press(Message::ButtonClicked("Medium Filled"))
                             .padding(8)
@@ -535,16 +534,16 @@ impl ModernThemeDemo {
                     .width(Length::Fill)
                     .center_x(Length::Fill),
                 ],
-                vertical_space().height(15),
+                space().height(15),
                 
                 // Large buttons row
                 text("Large").size(16).style(Modern::secondary_text()),
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     // Plain large button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::plain_button())
                             .on_press(Message::ButtonClicked("Large Plain"))
                             .padding(12)
@@ -554,7 +553,7 @@ impl ModernThemeDemo {
                     
                     // Gray large button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::gray_button())
                             .on_press(Message::ButtonClicked("Large Gray"))
                             .padding(12)
@@ -564,7 +563,7 @@ impl ModernThemeDemo {
                     
                     // Tinted large button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::blue_tinted_button())
                             .on_press(Message::ButtonClicked("Large Tinted"))
                             .padding(12)
@@ -574,7 +573,7 @@ impl ModernThemeDemo {
                     
                     // Filled large button
                     container(
-                        button(row![text("⏵").shaping(text::Shaping::Advanced), horizontal_space().width(5), text("Play")])
+                        button(row![text("⏵").shaping(text::Shaping::Advanced), space().width(5), text("Play")])
                             .style(Modern::primary_button())
                             .on_press(Message::ButtonClicked("Large Filled"))
                             .padding(12)
@@ -584,9 +583,9 @@ impl ModernThemeDemo {
                 ],
                 
                 // Additional tinted color variants
-                vertical_space().height(15),
+                space().height(15),
                 text("Tinted Button Colors").size(16),
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     container(
@@ -610,7 +609,7 @@ impl ModernThemeDemo {
                     )
                     .width(Length::Fill),
                 ],
-                vertical_space().height(5),
+                space().height(5),
                 
                 row![
                     container(
@@ -644,7 +643,7 @@ impl ModernThemeDemo {
         let container_showcase = container(
             column![
                 text("Container Styles").size(20),
-                vertical_space().height(10),
+                space().height(10),
                 
                 // Card container
                 container(
@@ -653,7 +652,7 @@ impl ModernThemeDemo {
                 .style(Modern::card_container())
                 .padding(10)
                 .width(Length::Fill),
-                vertical_space().height(5),
+                space().height(5),
 
                 // Sheet container
                 container(
@@ -662,7 +661,7 @@ impl ModernThemeDemo {
                 .style(Modern::sheet_container())
                 .padding(10)
                 .width(Length::Fill),
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Group container
                 container(
@@ -671,7 +670,7 @@ impl ModernThemeDemo {
                 .style(Modern::group_container())
                 .padding(10)
                 .width(Length::Fill),
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Sidebar container
                 container(
@@ -680,7 +679,7 @@ impl ModernThemeDemo {
                 .style(Modern::sidebar_container())
                 .padding(10)
                 .width(Length::Fill),
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Accent container
                 container(
@@ -689,7 +688,7 @@ impl ModernThemeDemo {
                 .style(Modern::accent_container())
                 .padding(10)
                 .width(Length::Fill),
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Toolbar
                 container(
@@ -698,7 +697,7 @@ impl ModernThemeDemo {
                 .style(Modern::toolbar_container())
                 .padding(10)
                 .width(Length::Fill),
-                vertical_space().height(5),
+                space().height(5),
                 
                 // Floating container
                 container(
@@ -717,18 +716,18 @@ impl ModernThemeDemo {
         let form_controls_section = container(
                 column![
                     text("Form Controls").size(20),
-                    vertical_space().height(10),
+                    space().height(10),
                     
                     // Text inputs
                     text("Text Inputs").size(16),
-                    vertical_space().height(5),
+                    space().height(5),
                     
                     // Standard text input
                     text_input("Standard text input...", &self.text_value)
                         .style(Modern::text_input())
                         .on_input(Message::TextInputChanged)
                         .padding(10),
-                    vertical_space().height(5),
+                    space().height(5),
                         
                     // Password input with reveal checkbox
                     row![
@@ -737,46 +736,50 @@ impl ModernThemeDemo {
                             .on_input(Message::PasswordChanged)
                             .secure(!self.show_password)
                             .padding(10),
-                        horizontal_space().width(10),
-                        checkbox("Show", self.show_password)
+                        space().width(10),
+                        checkbox(self.show_password)
+                            .label("Show")
                             .style(Modern::checkbox())
                             .on_toggle(Message::TogglePasswordVisibility),
                     ],
-                    vertical_space().height(5),
+                    space().height(5),
                     
                     // Search input
                     text_input("Search...", &self.text_value)
                         .style(Modern::search_input())
                         .on_input(Message::TextInputChanged)
                         .padding(10),
-                    vertical_space().height(10),
+                    space().height(10),
 
                     // Inline input
                     text_input("Inline input", &self.text_value)
                         .style(Modern::inline_text_input())
                         .on_input(Message::TextInputChanged),
-                    vertical_space().height(10),
+                    space().height(10),
                     
                     // Checkboxes
                     text("Checkboxes").size(16),
-                    vertical_space().height(5),
+                    space().height(5),
                     
                     row![
-                        checkbox("Unchecked", false)
+                        checkbox(false)
+                            .label("Unchecked")
                             .style(Modern::checkbox()),
-                        horizontal_space().width(20),
-                        checkbox("Checked", true)
+                        space().width(20),
+                        checkbox(true)
+                            .label("Checked")
                             .style(Modern::checkbox()),
-                        horizontal_space().width(20),
-                        checkbox("Interactive", self.checkbox_value)
+                        space().width(20),
+                        checkbox(self.checkbox_value)
+                            .label("Interactive")
                             .style(Modern::checkbox())
                             .on_toggle(Message::CheckboxToggled),
                     ],
-                    vertical_space().height(10),
+                    space().height(10),
                     
                     // Radio buttons
                     text("Radio Buttons").size(16),
-                    vertical_space().height(5),
+                    space().height(5),
                     
                     row![
                         radio(
@@ -786,7 +789,7 @@ impl ModernThemeDemo {
                             Message::RadioSelected
                         )
                         .style(Modern::radio()),
-                        horizontal_space().width(20),
+                        space().width(20),
                         radio(
                             "Option 2", 
                             RadioOption::Option2, 
@@ -794,7 +797,7 @@ impl ModernThemeDemo {
                             Message::RadioSelected
                         )
                         .style(Modern::radio()),
-                        horizontal_space().width(20),
+                        space().width(20),
                         radio(
                             "Option 3", 
                             RadioOption::Option3, 
@@ -803,11 +806,11 @@ impl ModernThemeDemo {
                         )
                         .style(Modern::radio()),
                     ],
-                    vertical_space().height(10),
+                    space().height(10),
                     
                     // Pick list
                     text("Pick List").size(16),
-                    vertical_space().height(5),
+                    space().height(5),
                     
                     pick_list(
                         &[Fruit::Apple, Fruit::Banana, Fruit::Orange, Fruit::Pear][..],
@@ -817,11 +820,11 @@ impl ModernThemeDemo {
                     .style(Modern::pick_list())
                     .placeholder("Choose a fruit...")
                     .padding(10),
-                    vertical_space().height(10),
+                    space().height(10),
 
                     // Combo Box
                     text("Combo Box").size(16),
-                    vertical_space().height(5),
+                    space().height(5),
 
                     combo_box(
                         &self.fruits_combo,
@@ -833,31 +836,31 @@ impl ModernThemeDemo {
                     .menu_style(Modern::combo_box_menu())
                     .width(250)
                     .padding(10),
-                    vertical_space().height(10),
+                    space().height(10),
                     
                     // Text styles showcase 
                     text("Text Styles").size(16),
-                    vertical_space().height(5),
+                    space().height(5),
                     
                     text("Primary Text Style").style(Modern::primary_text()),
                     text("Secondary Text Style").style(Modern::secondary_text()),
                     text("Tertiary Text Style").style(Modern::tertiary_text()),
                     text("Link Style Text").style(Modern::link_text()),
                     
-                    vertical_space().height(5),
+                    space().height(5),
                     row![
                         text("Red Text").style(Modern::red_text()),
-                        horizontal_space().width(10),
+                        space().width(10),
                         text("Blue Text").style(Modern::blue_text()),
-                        horizontal_space().width(10),
+                        space().width(10),
                         text("Green Text").style(Modern::green_text()),
                     ],
-                    vertical_space().height(5),
+                    space().height(5),
                     row![
                         text("Orange Text").style(Modern::orange_text()),
-                        horizontal_space().width(10),
+                        space().width(10),
                         text("Purple Text").style(Modern::purple_text()),
-                        horizontal_space().width(10),
+                        space().width(10),
                         text("Pink Text").style(Modern::pink_text()),
                     ],
                 ]
@@ -871,15 +874,15 @@ impl ModernThemeDemo {
             header,
             theme_section,
             color_showcase,
-            vertical_space().height(10),
+            space().height(10),
             button_showcase,
-            vertical_space().height(10),
+            space().height(10),
             extended_button_showcase,
-            vertical_space().height(10),
+            space().height(10),
             button_variants_showcase,
-            vertical_space().height(10),
+            space().height(10),
             container_showcase,
-            vertical_space().height(10),
+            space().height(10),
             form_controls_section,
         ]
         .spacing(0)
@@ -904,14 +907,8 @@ fn color_tile<'a>(name: &'a str, light_color: Color, dark_color: Color, current_
     let current_color = match current_theme {
         Theme::Light => light_color,
         Theme::Dark => dark_color,
-        Theme::Custom(custom_name) => {
-            // Check if the custom theme name contains "Dark"
-            if custom_name.to_string().contains("Dark") {
-                dark_color
-            } else {
-                light_color
-            }
-        }
+        // Check if the custom theme name contains "Dark"
+        Theme::Custom(custom_name) if custom_name.to_string().contains("Dark") => dark_color,
         _ => {light_color}
     };
     
@@ -931,6 +928,7 @@ fn color_tile<'a>(name: &'a str, light_color: Color, dark_color: Color, current_
                 color: Color::TRANSPARENT,
             },
             shadow: Shadow::default(),
+            snap: true,
         }
     })
     .padding(5)
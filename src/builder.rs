@@ -0,0 +1,224 @@
+//! Custom theme construction for the Modern theme.
+//!
+//! This module provides the `ModernThemeBuilder`, which starts from the
+//! standard Modern palette and lets individual palette colors be replaced,
+//! with an optional contrast check before the theme is created.
+
+use iced::{Color, Theme};
+
+use crate::colors::{
+    contrast_ratio, is_dark_mode, on_color, ContrastIssue, CONTRAST_AA, CONTRAST_AA_LARGE,
+};
+use crate::theme::Modern;
+
+/// Builder for custom iced themes based on the Modern palette
+///
+/// Unset colors keep the standard Modern light or dark values. The theme
+/// name drives the Modern widget styles' dark mode detection, so names of
+/// dark themes should contain "Dark".
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModernThemeBuilder {
+    name: String,
+    palette: iced::theme::Palette,
+}
+
+impl ModernThemeBuilder {
+    /// Start from the standard light or dark Modern palette
+    pub fn new(dark_mode: bool) -> Self {
+        let name = if dark_mode {
+            "Modern Custom Dark"
+        } else {
+            "Modern Custom Light"
+        };
+
+        Self {
+            name: String::from(name),
+            palette: Modern::palette(dark_mode),
+        }
+    }
+
+    /// Set the theme name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the window background color
+    pub fn background(mut self, color: Color) -> Self {
+        self.palette.background = color;
+        self
+    }
+
+    /// Set the body text color
+    pub fn text(mut self, color: Color) -> Self {
+        self.palette.text = color;
+        self
+    }
+
+    /// Set the primary (accent) color
+    pub fn primary(mut self, color: Color) -> Self {
+        self.palette.primary = color;
+        self
+    }
+
+    /// Set the success color
+    pub fn success(mut self, color: Color) -> Self {
+        self.palette.success = color;
+        self
+    }
+
+    /// Set the danger color
+    pub fn danger(mut self, color: Color) -> Self {
+        self.palette.danger = color;
+        self
+    }
+
+    /// Set the warning color
+    pub fn warning(mut self, color: Color) -> Self {
+        self.palette.warning = color;
+        self
+    }
+
+    /// Create the theme without any checks
+    pub fn build(self) -> Theme {
+        Theme::custom(self.name, self.palette)
+    }
+
+    /// Create the theme if its critical color pairs are legible
+    ///
+    /// Text must reach `CONTRAST_AA` and the primary color
+    /// `CONTRAST_AA_LARGE` against the background. Every pair that falls
+    /// short is reported in the error, as is a name whose light or dark mode
+    /// disagrees with the background.
+    pub fn build_checked(self) -> Result<Theme, ThemeBuildError> {
+        let palette = self.palette;
+        let theme = self.build();
+
+        // White text reads better than black on a dark background
+        let dark_background = on_color(palette.background) == on_color(Color::BLACK);
+        let name_mismatch = is_dark_mode(&theme) != dark_background;
+
+        let issues: Vec<ContrastIssue> = [
+            ("text", palette.text, CONTRAST_AA),
+            ("primary", palette.primary, CONTRAST_AA_LARGE),
+        ]
+        .into_iter()
+        .filter_map(|(foreground, color, required)| {
+            let ratio = contrast_ratio(color, palette.background);
+
+            (ratio < required).then_some(ContrastIssue {
+                foreground,
+                background: "background",
+                ratio,
+                required,
+            })
+        })
+        .collect();
+
+        if issues.is_empty() && !name_mismatch {
+            Ok(theme)
+        } else {
+            Err(ThemeBuildError {
+                issues,
+                name_mismatch,
+            })
+        }
+    }
+}
+
+impl Default for ModernThemeBuilder {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+/// Error returned by `ModernThemeBuilder::build_checked`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeBuildError {
+    /// Every critical color pair below its required contrast
+    pub issues: Vec<ContrastIssue>,
+    /// Whether the name's dark mode ("Dark" in the name) disagrees with the
+    /// background, which would style every widget for the wrong mode
+    pub name_mismatch: bool,
+}
+
+impl std::fmt::Display for ThemeBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid theme: ")?;
+
+        for (index, issue) in self.issues.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{issue}")?;
+        }
+
+        if self.name_mismatch {
+            if !self.issues.is_empty() {
+                f.write_str("; ")?;
+            }
+            f.write_str("name does not match the background's light or dark mode")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ThemeBuildError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_builders_pass_the_checks() {
+        assert!(ModernThemeBuilder::default().build_checked().is_ok());
+        assert!(ModernThemeBuilder::new(true).build_checked().is_ok());
+    }
+
+    #[test]
+    fn text_matching_the_background_is_reported() {
+        let background = Modern::palette(false).background;
+        let error = ModernThemeBuilder::default()
+            .text(background)
+            .build_checked()
+            .unwrap_err();
+
+        assert_eq!(error.issues.len(), 1);
+        assert_eq!(error.issues[0].foreground, "text");
+        assert!(!error.name_mismatch);
+    }
+
+    #[test]
+    fn low_contrast_primary_is_reported_separately() {
+        let error = ModernThemeBuilder::default()
+            .primary(Color::from_rgb(0.9, 0.9, 0.92))
+            .build_checked()
+            .unwrap_err();
+
+        let foregrounds: Vec<_> = error.issues.iter().map(|issue| issue.foreground).collect();
+        assert_eq!(foregrounds, ["primary"]);
+        assert_eq!(error.issues[0].required, CONTRAST_AA_LARGE);
+    }
+
+    #[test]
+    fn name_must_match_the_background() {
+        let dark = Modern::palette(true);
+        let error = ModernThemeBuilder::default()
+            .background(dark.background)
+            .text(dark.text)
+            .build_checked()
+            .unwrap_err();
+
+        assert!(error.issues.is_empty());
+        assert!(error.name_mismatch);
+
+        let renamed = ModernThemeBuilder::default()
+            .name("Custom Dark")
+            .background(dark.background)
+            .text(dark.text)
+            .primary(dark.primary)
+            .build_checked();
+        assert!(renamed.is_ok());
+    }
+}
@@ -3,9 +3,17 @@
 //! This module provides all the standard colors from Modern's design system,
 //! organized into namespaces for both light and dark modes.
 
+use std::cell::RefCell;
+
+use iced::theme::Base;
 use iced::Color;
+use palette::color_difference::Wcag21RelativeContrast;
+use palette::{Clamp, FromColor, Hsl, Hsv, Mix, Oklch, RgbHue, Srgb};
+
+use crate::config::ModernConfig;
 
 /// Complete Modern system color palette
+#[allow(clippy::module_inception)]
 pub mod colors {
     
     /// mobile system colors - standard variants
@@ -57,6 +65,10 @@ pub mod colors {
         pub const PINK: Color = Color::from_rgb(1.0, 0.17, 0.34);       // #FF2D55
         pub const PINK_DARK: Color = Color::from_rgb(1.0, 0.22, 0.37);  // #FF375F
         
+        /// Amber - softer warning fill that stays legible with dark text
+        pub const AMBER: Color = Color::from_rgb(1.0, 0.75, 0.0);       // #FFBF00
+        pub const AMBER_DARK: Color = Color::from_rgb(1.0, 0.78, 0.16); // #FFC729
+        
         /// Brown - warm, natural color for earthy or traditional UI elements
         pub const BROWN: Color = Color::from_rgb(0.64, 0.52, 0.31);     // #A2845E
         pub const BROWN_DARK: Color = Color::from_rgb(0.67, 0.56, 0.39); // #AC8E68
@@ -106,6 +118,12 @@ pub mod colors {
         pub const SECONDARY_LIGHT: Color = Color::from_rgb(0.95, 0.95, 0.97); // #F2F2F7
         /// Tertiary background (slightly darker gray)
         pub const TERTIARY_LIGHT: Color = Color::from_rgb(0.90, 0.90, 0.92);  // #E5E5EA
+        /// Primary fill (translucent gray for thin and small shapes, 20% alpha)
+        pub const PRIMARY_FILL_LIGHT: Color = Color::from_rgba(0.47, 0.47, 0.5, 0.2);    // #787880 @ 20%
+        /// Secondary fill (translucent gray for medium shapes, 16% alpha)
+        pub const SECONDARY_FILL_LIGHT: Color = Color::from_rgba(0.47, 0.47, 0.5, 0.16); // #787880 @ 16%
+        /// Tertiary fill (translucent gray for large shapes like badges and chips, 12% alpha)
+        pub const TERTIARY_FILL_LIGHT: Color = Color::from_rgba(0.46, 0.46, 0.5, 0.12);  // #767680 @ 12%
         
         // Dark mode
         /// Primary background color (black/very dark gray)
@@ -114,6 +132,12 @@ pub mod colors {
         pub const SECONDARY_DARK: Color = Color::from_rgb(0.17, 0.17, 0.18);  // #2C2C2E
         /// Tertiary background (slightly lighter dark gray)
         pub const TERTIARY_DARK: Color = Color::from_rgb(0.22, 0.22, 0.23);   // #38383A
+        /// Primary fill (translucent gray for thin and small shapes, 36% alpha)
+        pub const PRIMARY_FILL_DARK: Color = Color::from_rgba(0.47, 0.47, 0.5, 0.36);    // #787880 @ 36%
+        /// Secondary fill (translucent gray for medium shapes, 32% alpha)
+        pub const SECONDARY_FILL_DARK: Color = Color::from_rgba(0.47, 0.47, 0.5, 0.32);  // #787880 @ 32%
+        /// Tertiary fill (translucent gray for large shapes like badges and chips, 24% alpha)
+        pub const TERTIARY_FILL_DARK: Color = Color::from_rgba(0.46, 0.46, 0.5, 0.24);   // #767680 @ 24%
     }
     
     /// Text colors for various UI states
@@ -225,6 +249,68 @@ pub mod colors {
         /// Accessible Blue (Dark)
         pub const BLUE_DARK: Color = Color::from_rgb(0.39, 0.66, 1.0);       // #64A8FF
     }
+    
+    /// Warm, low-blue colors for the sepia reading theme
+    pub mod sepia {
+        use iced::Color;
+        
+        // Surfaces
+        /// Cream page background
+        pub const BACKGROUND: Color = Color::from_rgb(0.96, 0.93, 0.85);       // #F5EDD9
+        /// Lighter paper for cards and inputs
+        pub const SECONDARY_BACKGROUND: Color = Color::from_rgb(0.98, 0.96, 0.91); // #FAF5E8
+        /// Deeper cream for grouped content
+        pub const TERTIARY_BACKGROUND: Color = Color::from_rgb(0.93, 0.89, 0.8); // #EDE3CC
+        /// Fill for system buttons and highlights
+        pub const SYSTEM_BACKGROUND: Color = Color::from_rgb(0.91, 0.86, 0.76); // #E8DBC2
+        
+        // Text
+        /// Dark brown body text
+        pub const TEXT: Color = Color::from_rgb(0.36, 0.27, 0.21);             // #5C4536
+        /// Supporting text
+        pub const SECONDARY_TEXT: Color = Color::from_rgb(0.47, 0.39, 0.31);   // #78634F
+        /// Tertiary text and placeholders
+        pub const TERTIARY_TEXT: Color = Color::from_rgb(0.6, 0.53, 0.45);     // #998773
+        
+        // Lines
+        /// Separators
+        pub const SEPARATOR: Color = Color::from_rgb(0.85, 0.8, 0.69);         // #D9CCB0
+        /// Input borders
+        pub const BORDER: Color = Color::from_rgb(0.8, 0.73, 0.61);            // #CCBA9C
+        /// Unselected control borders
+        pub const INACTIVE_BORDER: Color = Color::from_rgb(0.75, 0.68, 0.57);  // #BFAD91
+        
+        // Muted accents
+        /// Muted blue accent
+        pub const BLUE: Color = Color::from_rgb(0.21, 0.38, 0.5);              // #366180
+        /// Muted green
+        pub const GREEN: Color = Color::from_rgb(0.33, 0.45, 0.2);             // #547333
+        /// Muted red
+        pub const RED: Color = Color::from_rgb(0.64, 0.25, 0.17);              // #A3402B
+        /// Muted orange
+        pub const ORANGE: Color = Color::from_rgb(0.7, 0.42, 0.15);            // #B36B26
+    }
+    
+    /// Pure-black surfaces for the OLED dark theme
+    pub mod oled {
+        use iced::Color;
+        
+        // Surfaces
+        /// True black window background
+        pub const BACKGROUND: Color = Color::BLACK;                            // #000000
+        /// Sidebars, just off black
+        pub const SIDEBAR_BACKGROUND: Color = Color::from_rgb(0.05, 0.05, 0.05); // #0D0D0D
+        /// Cards, groups and inputs
+        pub const SECONDARY_BACKGROUND: Color = Color::from_rgb(0.09, 0.09, 0.09); // #171717
+        /// Sheets and other raised surfaces
+        pub const TERTIARY_BACKGROUND: Color = Color::from_rgb(0.13, 0.13, 0.14); // #212124
+        
+        // Lines
+        /// Separators
+        pub const SEPARATOR: Color = Color::from_rgb(0.22, 0.22, 0.23);        // #38383A
+        /// Input borders
+        pub const BORDER: Color = Color::from_rgb(0.33, 0.33, 0.35);           // #545458
+    }
 }
 
 // Common color constants for backward compatibility
@@ -238,6 +324,7 @@ pub const MODERN_RED_LIGHT: Color = colors::system::RED;
 pub const MODERN_RED_DARK: Color = colors::system::RED_DARK;
 
 /// Complete set of theme colors used across the Modern-styled UI
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ThemeColors {
     // Primary colors
     pub blue: Color,
@@ -255,6 +342,17 @@ pub struct ThemeColors {
     pub secondary_background: Color,
     pub tertiary_background: Color,
     
+    // Surface levels (background → surface → elevated surface)
+    /// Inset content laid on the background, such as grouped lists
+    pub surface: Color,
+    /// Raised content above the surface, such as sheets and modals
+    pub surface_elevated: Color,
+    
+    // Fill colors (translucent, layered over any background)
+    pub fill_primary: Color,
+    pub fill_secondary: Color,
+    pub fill_tertiary: Color,
+    
     // Element colors
     pub card_bg: Color,
     pub system_bg: Color,
@@ -277,9 +375,100 @@ pub struct ThemeColors {
     pub teal: Color,
     pub mint: Color,
     pub yellow: Color,
+    pub amber: Color,
     pub brown: Color,
 }
 
+impl ThemeColors {
+    /// The accent (blue) as hue in degrees (0-360), saturation and lightness (0-1)
+    pub fn accent_hsl(&self) -> (f32, f32, f32) {
+        to_hsl(self.blue)
+    }
+    
+    /// The accent (blue) as hue in degrees (0-360), saturation and value (0-1)
+    pub fn accent_hsv(&self) -> (f32, f32, f32) {
+        to_hsv(self.blue)
+    }
+    
+    /// Apply a color adjustment to every role
+    pub fn map(&self, adjust: impl Fn(Color) -> Color) -> Self {
+        Self {
+            blue: adjust(self.blue),
+            green: adjust(self.green),
+            orange: adjust(self.orange),
+            red: adjust(self.red),
+            text: adjust(self.text),
+            secondary_text: adjust(self.secondary_text),
+            tertiary_text: adjust(self.tertiary_text),
+            background: adjust(self.background),
+            secondary_background: adjust(self.secondary_background),
+            tertiary_background: adjust(self.tertiary_background),
+            surface: adjust(self.surface),
+            surface_elevated: adjust(self.surface_elevated),
+            fill_primary: adjust(self.fill_primary),
+            fill_secondary: adjust(self.fill_secondary),
+            fill_tertiary: adjust(self.fill_tertiary),
+            card_bg: adjust(self.card_bg),
+            system_bg: adjust(self.system_bg),
+            inactive_border: adjust(self.inactive_border),
+            placeholder: adjust(self.placeholder),
+            input_bg: adjust(self.input_bg),
+            input_border: adjust(self.input_border),
+            separator: adjust(self.separator),
+            selection: adjust(self.selection),
+            link: adjust(self.link),
+            pink: adjust(self.pink),
+            purple: adjust(self.purple),
+            indigo: adjust(self.indigo),
+            teal: adjust(self.teal),
+            mint: adjust(self.mint),
+            yellow: adjust(self.yellow),
+            amber: adjust(self.amber),
+            brown: adjust(self.brown),
+        }
+    }
+    
+    /// Every color role paired with its name, in declaration order
+    ///
+    /// Meant for diagnostics and exports rather than styling hot paths.
+    pub fn entries(&self) -> Vec<(&'static str, Color)> {
+        vec![
+            ("blue", self.blue),
+            ("green", self.green),
+            ("orange", self.orange),
+            ("red", self.red),
+            ("text", self.text),
+            ("secondary_text", self.secondary_text),
+            ("tertiary_text", self.tertiary_text),
+            ("background", self.background),
+            ("secondary_background", self.secondary_background),
+            ("tertiary_background", self.tertiary_background),
+            ("surface", self.surface),
+            ("surface_elevated", self.surface_elevated),
+            ("fill_primary", self.fill_primary),
+            ("fill_secondary", self.fill_secondary),
+            ("fill_tertiary", self.fill_tertiary),
+            ("card_bg", self.card_bg),
+            ("system_bg", self.system_bg),
+            ("inactive_border", self.inactive_border),
+            ("placeholder", self.placeholder),
+            ("input_bg", self.input_bg),
+            ("input_border", self.input_border),
+            ("separator", self.separator),
+            ("selection", self.selection),
+            ("link", self.link),
+            ("pink", self.pink),
+            ("purple", self.purple),
+            ("indigo", self.indigo),
+            ("teal", self.teal),
+            ("mint", self.mint),
+            ("yellow", self.yellow),
+            ("amber", self.amber),
+            ("brown", self.brown),
+        ]
+    }
+}
+
 /// Helper function to determine if we're in dark mode
 pub fn is_dark_mode(theme: &iced::Theme) -> bool {
     match theme {
@@ -289,11 +478,70 @@ pub fn is_dark_mode(theme: &iced::Theme) -> bool {
     }
 }
 
+/// Helper function to determine if this is a high-contrast Modern theme
+pub fn is_high_contrast(theme: &iced::Theme) -> bool {
+    match theme {
+        iced::Theme::Custom(custom) => custom.to_string().contains("High Contrast"),
+        _ => false
+    }
+}
+
+/// Helper function to determine if this is the sepia reading theme
+///
+/// Only the light theme counts: a dark theme derived from it (e.g. with
+/// `Modern::auto_dark`) has its own inverted palette and keeps dark colors.
+pub fn is_sepia(theme: &iced::Theme) -> bool {
+    match theme {
+        iced::Theme::Custom(custom) => custom.to_string().contains("Sepia") && !is_dark_mode(theme),
+        _ => false
+    }
+}
+
+/// Helper function to determine if this is the pure-black OLED dark theme
+pub fn is_oled(theme: &iced::Theme) -> bool {
+    match theme {
+        iced::Theme::Custom(custom) => custom.to_string().contains("OLED"),
+        _ => false
+    }
+}
+
+thread_local! {
+    /// Colors of the most recently styled theme, keyed by theme name and background
+    ///
+    /// Thread-local so renderers on other threads never contend for it.
+    static THEME_COLORS_CACHE: RefCell<Option<(String, Color, ThemeColors)>> = const { RefCell::new(None) };
+}
+
 /// Get the appropriate colors based on theme mode
+///
+/// Every widget calls this on every draw, so the result for the last theme
+/// seen on the current thread is cached and reused until the theme changes.
 pub fn get_theme_colors(theme: &iced::Theme) -> ThemeColors {
+    let name = theme.name();
+    let background = theme.palette().background;
+    
+    THEME_COLORS_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        
+        match cache.as_ref() {
+            Some((cached_name, cached_background, colors)) if cached_name == name && *cached_background == background => *colors,
+            _ => {
+                let colors = compute_theme_colors(theme, true);
+                *cache = Some((name.to_owned(), background, colors));
+                colors
+            }
+        }
+    })
+}
+
+/// Build the theme colors from scratch, bypassing the cache
+///
+/// `soften_accents` applies `soften_dark_accent` to the accents of dark,
+/// non-high-contrast themes (see `ModernConfig::desaturate_dark_accents`).
+fn compute_theme_colors(theme: &iced::Theme, soften_accents: bool) -> ThemeColors {
     let is_dark = is_dark_mode(theme);
     
-    ThemeColors {
+    let mut colors = ThemeColors {
         // Primary colors
         blue: if is_dark { colors::system::BLUE_DARK } else { colors::system::BLUE },
         green: if is_dark { colors::system::GREEN_DARK } else { colors::system::GREEN },
@@ -310,6 +558,15 @@ pub fn get_theme_colors(theme: &iced::Theme) -> ThemeColors {
         secondary_background: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::SECONDARY_LIGHT },
         tertiary_background: if is_dark { colors::fill::TERTIARY_DARK } else { colors::fill::TERTIARY_LIGHT },
         
+        // Surface levels
+        surface: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::SECONDARY_LIGHT },
+        surface_elevated: if is_dark { colors::fill::TERTIARY_DARK } else { colors::fill::SECONDARY_LIGHT },
+        
+        // Fill colors
+        fill_primary: if is_dark { colors::fill::PRIMARY_FILL_DARK } else { colors::fill::PRIMARY_FILL_LIGHT },
+        fill_secondary: if is_dark { colors::fill::SECONDARY_FILL_DARK } else { colors::fill::SECONDARY_FILL_LIGHT },
+        fill_tertiary: if is_dark { colors::fill::TERTIARY_FILL_DARK } else { colors::fill::TERTIARY_FILL_LIGHT },
+        
         // Element colors
         card_bg: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::BACKGROUND_LIGHT },
         system_bg: if is_dark { colors::gray::GRAY4_DARK } else { colors::gray::GRAY5_LIGHT },
@@ -332,6 +589,454 @@ pub fn get_theme_colors(theme: &iced::Theme) -> ThemeColors {
         teal: if is_dark { colors::system::TEAL_DARK } else { colors::system::TEAL },
         mint: if is_dark { colors::system::MINT_DARK } else { colors::system::MINT },
         yellow: if is_dark { colors::system::YELLOW_DARK } else { colors::system::YELLOW },
+        amber: if is_dark { colors::system::AMBER_DARK } else { colors::system::AMBER },
         brown: if is_dark { colors::system::BROWN_DARK } else { colors::system::BROWN },
+    };
+    
+    // Sepia keeps the light layout but swaps in warm paper surfaces and muted accents
+    if is_sepia(theme) {
+        colors.blue = colors::sepia::BLUE;
+        colors.green = colors::sepia::GREEN;
+        colors.orange = colors::sepia::ORANGE;
+        colors.red = colors::sepia::RED;
+        colors.link = colors.blue;
+        colors.selection = colors.blue.scale_alpha(0.3);
+        
+        colors.text = colors::sepia::TEXT;
+        colors.secondary_text = colors::sepia::SECONDARY_TEXT;
+        colors.tertiary_text = colors::sepia::TERTIARY_TEXT;
+        colors.placeholder = colors::sepia::TERTIARY_TEXT;
+        
+        colors.background = colors::sepia::BACKGROUND;
+        colors.secondary_background = colors::sepia::SECONDARY_BACKGROUND;
+        colors.tertiary_background = colors::sepia::TERTIARY_BACKGROUND;
+        colors.surface = colors::sepia::TERTIARY_BACKGROUND;
+        colors.surface_elevated = colors::sepia::SECONDARY_BACKGROUND;
+        colors.card_bg = colors::sepia::SECONDARY_BACKGROUND;
+        colors.input_bg = colors::sepia::SECONDARY_BACKGROUND;
+        colors.system_bg = colors::sepia::SYSTEM_BACKGROUND;
+        
+        colors.separator = colors::sepia::SEPARATOR;
+        colors.input_border = colors::sepia::BORDER;
+        colors.inactive_border = colors::sepia::INACTIVE_BORDER;
+    }
+    
+    // OLED keeps the dark accents but drops surfaces to true black with lifted grays for separation
+    if is_oled(theme) {
+        colors.background = colors::oled::BACKGROUND;
+        colors.secondary_background = colors::oled::SECONDARY_BACKGROUND;
+        colors.tertiary_background = colors::oled::TERTIARY_BACKGROUND;
+        colors.surface = colors::oled::SECONDARY_BACKGROUND;
+        colors.surface_elevated = colors::oled::TERTIARY_BACKGROUND;
+        colors.card_bg = colors::oled::SECONDARY_BACKGROUND;
+        colors.input_bg = colors::oled::SECONDARY_BACKGROUND;
+        
+        colors.separator = colors::oled::SEPARATOR;
+        colors.input_border = colors::oled::BORDER;
+    }
+    
+    // High contrast swaps in the accessible accents and strengthens faint text and borders
+    if is_high_contrast(theme) {
+        colors.blue = if is_dark { colors::accessibility::BLUE_DARK } else { colors::accessibility::BLUE_LIGHT };
+        colors.green = if is_dark { colors::accessibility::GREEN_DARK } else { colors::accessibility::GREEN_LIGHT };
+        colors.orange = if is_dark { colors::accessibility::ORANGE_DARK } else { colors::accessibility::ORANGE_LIGHT };
+        colors.red = if is_dark { colors::accessibility::RED_DARK } else { colors::accessibility::RED_LIGHT };
+        colors.yellow = if is_dark { colors::accessibility::YELLOW_DARK } else { colors::accessibility::YELLOW_LIGHT };
+        colors.link = colors.blue;
+        colors.selection = colors.blue.scale_alpha(0.3);
+        
+        colors.tertiary_text = colors.secondary_text;
+        colors.placeholder = colors.secondary_text;
+        colors.inactive_border = colors::text::TERTIARY_LIGHT;
+        colors.input_border = colors::text::TERTIARY_LIGHT;
+        colors.separator = colors::text::TERTIARY_LIGHT;
+    }
+    
+    // Saturated accents glare on dark surfaces, so ease them off (high contrast keeps full strength)
+    if soften_accents && is_dark && !is_high_contrast(theme) {
+        for accent in [
+            &mut colors.blue, &mut colors.green, &mut colors.orange, &mut colors.red,
+            &mut colors.link, &mut colors.pink, &mut colors.purple, &mut colors.indigo,
+            &mut colors.teal, &mut colors.mint, &mut colors.yellow, &mut colors.amber,
+            &mut colors.brown,
+        ] {
+            *accent = soften_dark_accent(*accent);
+        }
+        colors.selection = colors.blue.scale_alpha(0.3);
+    }
+    
+    colors
+}
+
+/// Get the appropriate colors based on theme mode, honoring a `ModernConfig`
+pub fn get_theme_colors_with(theme: &iced::Theme, config: &ModernConfig) -> ThemeColors {
+    let mut colors = if config.desaturate_dark_accents {
+        get_theme_colors(theme)
+    } else {
+        // The cache holds the softened default, so opting out recomputes
+        compute_theme_colors(theme, false)
+    };
+    
+    // Brand accent replaces the system blue everywhere it acts as the accent
+    if let Some(accent) = config.accent {
+        colors.blue = accent;
+        colors.link = accent;
+        colors.selection = accent.scale_alpha(0.3);
+    }
+    
+    // Custom neutrals replace the system grays in the roles derived from them
+    if let Some(ramp) = config.gray_ramp {
+        let is_dark = is_dark_mode(theme);
+        let grays = ramp.grays(is_dark);
+        
+        colors.separator = ramp.separator(is_dark);
+        colors.inactive_border = if is_dark { grays[1] } else { grays[2] };
+        colors.system_bg = if is_dark { grays[3] } else { grays[4] };
+        colors.input_bg = if is_dark { grays[4] } else { grays[5] };
+    }
+    
+    colors.separator = colors.separator.scale_alpha(config.separator_alpha.clamp(0.0, 1.0));
+    
+    if config.warmth != 0.0 {
+        colors = colors.map(|color| shift_temperature(color, config.warmth));
+    }
+    
+    colors
+}
+
+/// Minimum WCAG AA contrast ratio for normal text
+pub const CONTRAST_AA: f32 = 4.5;
+/// Minimum WCAG AA contrast ratio for large text (and UI components)
+pub const CONTRAST_AA_LARGE: f32 = 3.0;
+
+/// A foreground/background role pair that falls short of its WCAG contrast target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastIssue {
+    /// Name of the foreground role, e.g. "secondary_text" or "button text"
+    pub foreground: &'static str,
+    /// Name of the background role, e.g. "card_bg" or "blue"
+    pub background: &'static str,
+    /// The measured contrast ratio
+    pub ratio: f32,
+    /// The required contrast ratio (`CONTRAST_AA` or `CONTRAST_AA_LARGE`)
+    pub required: f32,
+}
+
+impl std::fmt::Display for ContrastIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} on {}: {:.2}:1, needs {:.1}:1",
+            self.foreground, self.background, self.ratio, self.required
+        )
+    }
+}
+
+/// Check the text and background role pairs of a set of theme colors against WCAG AA
+///
+/// Body text roles must reach `CONTRAST_AA` on every surface; tertiary
+/// text, placeholders, links and the text of filled buttons must reach
+/// `CONTRAST_AA_LARGE`. Returns every pair that falls short.
+pub fn audit_contrast(colors: &ThemeColors) -> Vec<ContrastIssue> {
+    let surfaces = [
+        ("background", colors.background),
+        ("secondary_background", colors.secondary_background),
+        ("card_bg", colors.card_bg),
+        ("input_bg", colors.input_bg),
+    ];
+    
+    let mut pairs = Vec::new();
+    
+    for (background, surface) in surfaces {
+        pairs.push(("text", colors.text, background, surface, CONTRAST_AA));
+        pairs.push(("secondary_text", colors.secondary_text, background, surface, CONTRAST_AA));
+        pairs.push(("tertiary_text", colors.tertiary_text, background, surface, CONTRAST_AA_LARGE));
+        pairs.push(("placeholder", colors.placeholder, background, surface, CONTRAST_AA_LARGE));
+        pairs.push(("link", colors.link, background, surface, CONTRAST_AA_LARGE));
+    }
+    
+    for (background, fill) in [("blue", colors.blue), ("green", colors.green), ("red", colors.red)] {
+        pairs.push(("button text", accent_label_color(fill), background, fill, CONTRAST_AA_LARGE));
+    }
+    pairs.push(("warning button text", on_color(colors.orange), "orange", colors.orange, CONTRAST_AA_LARGE));
+    
+    pairs
+        .into_iter()
+        .filter_map(|(foreground, fg, background, bg, required)| {
+            let ratio = contrast_ratio(fg, bg);
+            
+            (ratio < required).then_some(ContrastIssue { foreground, background, ratio, required })
+        })
+        .collect()
+}
+
+/// Relative luminance of a color as defined by WCAG 2.1 (alpha is ignored)
+pub fn relative_luminance(color: Color) -> f32 {
+    Srgb::new(color.r, color.g, color.b).relative_luminance().luma
+}
+
+/// Contrast ratio between two colors as defined by WCAG 2.1, from 1.0 to 21.0 (alpha is ignored)
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    Srgb::new(a.r, a.g, a.b).relative_contrast(Srgb::new(b.r, b.g, b.b))
+}
+
+/// Get the readable "on-color" for text and icons drawn over a filled background
+///
+/// Returns primary light-mode text (black) or dark-mode text (white),
+/// whichever has the higher contrast against `background`.
+pub fn on_color(background: Color) -> Color {
+    let dark_text = colors::text::PRIMARY_LIGHT;
+    let light_text = colors::text::PRIMARY_DARK;
+    
+    if contrast_ratio(background, dark_text) >= contrast_ratio(background, light_text) {
+        dark_text
+    } else {
+        light_text
+    }
+}
+
+/// Get the label color for a button filled with a saturated accent
+///
+/// White is kept wherever it reaches `CONTRAST_AA_LARGE` against `fill`;
+/// otherwise it is shaded with `ensure_contrast` just far enough to pass,
+/// e.g. on the softened dark green.
+pub fn accent_label_color(fill: Color) -> Color {
+    ensure_contrast(Color::WHITE, fill, CONTRAST_AA_LARGE)
+}
+
+/// Shift a color warmer or cooler by roughly `kelvin_shift` degrees of color temperature
+///
+/// Negative shifts warm the color (more red, less blue), positive shifts
+/// cool it. The shift is clamped to -2000..=2000 K, which moves the red and
+/// blue channels by at most 0.08 so accents stay recognizable; -500 to
+/// -1500 K gives a gentle evening warmth.
+pub fn shift_temperature(color: Color, kelvin_shift: f32) -> Color {
+    let amount = kelvin_shift.clamp(-2000.0, 2000.0) / 1000.0 * 0.04;
+    
+    Color {
+        r: (color.r - amount).clamp(0.0, 1.0),
+        b: (color.b + amount).clamp(0.0, 1.0),
+        ..color
+    }
+}
+
+/// Blend two colors, from `a` at `t = 0.0` to `b` at `t = 1.0`
+///
+/// Color channels are interpolated in linear light, which keeps midpoints
+/// from turning muddy or too dark; alpha is interpolated directly. `t` is
+/// clamped to 0-1.
+pub fn blend(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let a_linear = Srgb::new(a.r, a.g, a.b).into_linear();
+    let b_linear = Srgb::new(b.r, b.g, b.b).into_linear();
+    
+    let mixed: Srgb = Srgb::from_linear(a_linear.mix(b_linear, t));
+    
+    Color::from_rgba(mixed.red, mixed.green, mixed.blue, a.a + (b.a - a.a) * t)
+}
+
+/// Convert a color to hue in degrees (0-360), saturation and lightness (0-1); alpha is ignored
+pub fn to_hsl(color: Color) -> (f32, f32, f32) {
+    let hsl = Hsl::from_color(Srgb::new(color.r, color.g, color.b));
+    (hsl.hue.into_positive_degrees(), hsl.saturation, hsl.lightness)
+}
+
+/// Build an opaque color from hue in degrees, saturation and lightness (0-1)
+pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+    let rgb = Srgb::from_color(Hsl::new(RgbHue::from_degrees(hue), saturation, lightness)).clamp();
+    Color::from_rgb(rgb.red, rgb.green, rgb.blue)
+}
+
+/// Convert a color to hue in degrees (0-360), saturation and value (0-1); alpha is ignored
+pub fn to_hsv(color: Color) -> (f32, f32, f32) {
+    let hsv = Hsv::from_color(Srgb::new(color.r, color.g, color.b));
+    (hsv.hue.into_positive_degrees(), hsv.saturation, hsv.value)
+}
+
+/// Build an opaque color from hue in degrees, saturation and value (0-1)
+pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+    let rgb = Srgb::from_color(Hsv::new(RgbHue::from_degrees(hue), saturation, value)).clamp();
+    Color::from_rgb(rgb.red, rgb.green, rgb.blue)
+}
+
+/// Ease a saturated accent for dark backgrounds
+///
+/// Lowers the HSL saturation by 10% and raises the lightness by 0.02, like
+/// the softer dark-mode system colors. White button text and the contrast
+/// against dark backgrounds stay above `CONTRAST_AA_LARGE` for the
+/// built-in accents. Alpha is kept.
+pub fn soften_dark_accent(color: Color) -> Color {
+    let (hue, saturation, lightness) = to_hsl(color);
+    Color { a: color.a, ..from_hsl(hue, saturation * 0.9, (lightness + 0.02).min(1.0)) }
+}
+
+/// Rotate the hue of a color by `degrees`, keeping its saturation, lightness and alpha
+///
+/// Handy for deriving a series of distinguishable chart colors from the
+/// accent, e.g. `shift_hue(colors.blue, 360.0 / n * i)`.
+pub fn shift_hue(color: Color, degrees: f32) -> Color {
+    let (hue, saturation, lightness) = to_hsl(color);
+    Color { a: color.a, ..from_hsl(hue + degrees, saturation, lightness) }
+}
+
+/// Adjust a foreground color until it reaches `ratio` contrast against `background`
+///
+/// The color is blended step by step towards black or white, whichever
+/// reads better on `background`, so it keeps its hue as far as possible.
+/// Colors that already meet the ratio are returned unchanged.
+pub fn ensure_contrast(foreground: Color, background: Color, ratio: f32) -> Color {
+    let target = on_color(background);
+    let mut color = foreground;
+    let mut step = 0;
+    
+    while contrast_ratio(color, background) < ratio && step < 20 {
+        step += 1;
+        let amount = step as f32 * 0.05;
+        color = Color { a: foreground.a, ..blend(foreground, target, amount) };
+    }
+    
+    color
+}
+
+/// Flip a palette between light and dark, working in the Oklch perceptual space
+///
+/// Background and text swap lightness while keeping their hue and chroma.
+/// Accents keep hue and chroma too and are only nudged brighter (towards dark)
+/// or deeper (towards light), matching the system light/dark accent pairs.
+pub fn invert_palette(palette: &iced::theme::Palette) -> iced::theme::Palette {
+    let to_oklch = |color: Color| Oklch::from_color(Srgb::new(color.r, color.g, color.b));
+    let to_color = |oklch: Oklch, alpha: f32| {
+        let rgb = Srgb::from_color(oklch).clamp();
+        Color::from_rgba(rgb.red, rgb.green, rgb.blue, alpha)
+    };
+    
+    // Mirror lightness for surfaces and text
+    let invert = |color: Color| {
+        let mut oklch = to_oklch(color);
+        oklch.l = 1.0 - oklch.l;
+        to_color(oklch, color.a)
+    };
+    
+    let inverted_background = invert(palette.background);
+    let to_dark = relative_luminance(inverted_background) < 0.5;
+    
+    // Keep accents recognizable, just adjust them to the new background
+    let shift = |color: Color| {
+        let mut oklch = to_oklch(color);
+        oklch.l = if to_dark { (oklch.l + 0.05).min(1.0) } else { (oklch.l - 0.05).max(0.0) };
+        to_color(oklch, color.a)
+    };
+    
+    iced::theme::Palette {
+        background: inverted_background,
+        text: invert(palette.text),
+        primary: shift(palette.primary),
+        success: shift(palette.success),
+        danger: shift(palette.danger),
+        warning: shift(palette.warning),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::ModernVariant;
+    
+    /// Known shortfalls of the built-in themes, as (foreground, background) pairs
+    ///
+    /// Remove an entry once its palette is fixed; anything not listed fails the audit.
+    fn known_contrast_issues(variant: ModernVariant) -> &'static [(&'static str, &'static str)] {
+        match variant {
+            ModernVariant::Light => &[
+                ("tertiary_text", "secondary_background"),
+                ("placeholder", "secondary_background"),
+                ("tertiary_text", "input_bg"),
+                ("placeholder", "input_bg"),
+            ],
+            ModernVariant::Dark
+            | ModernVariant::OledDark
+            | ModernVariant::HighContrastLight
+            | ModernVariant::HighContrastDark => &[],
+            ModernVariant::Sepia => &[("tertiary_text", "background"), ("placeholder", "background")],
+        }
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn built_in_themes_pass_contrast_audit() {
+        for variant in ModernVariant::all() {
+            let known = known_contrast_issues(*variant);
+            let issues = audit_contrast(&get_theme_colors(&variant.theme()));
+            
+            for issue in &issues {
+                assert!(
+                    known.contains(&(issue.foreground, issue.background)),
+                    "{variant}: {issue}"
+                );
+            }
+            
+            // Keep the allow-list honest: fixed pairs must be removed from it
+            for (foreground, background) in known {
+                assert!(
+                    issues.iter().any(|issue| issue.foreground == *foreground && issue.background == *background),
+                    "{variant}: {foreground} on {background} now passes, remove it from the known issues"
+                );
+            }
+        }
+    }
+    
+    fn assert_close(a: Color, b: Color) {
+        for (x, y) in [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)] {
+            assert!((x - y).abs() < 1e-4, "{a:?} != {b:?}");
+        }
+    }
+    
+    #[test]
+    fn blend_endpoints_and_midpoint() {
+        let a = Color::from_rgba(0.1, 0.4, 0.9, 1.0);
+        let b = Color::from_rgba(0.9, 0.2, 0.3, 0.5);
+        
+        assert_close(blend(a, b, 0.0), a);
+        assert_close(blend(a, b, 1.0), b);
+        
+        // Black to white meets at half the light, which is sRGB ~0.735 rather than 0.5
+        let middle = blend(Color::BLACK, Color::WHITE, 0.5);
+        assert!((middle.r - 0.735).abs() < 0.005, "{middle:?}");
+        assert!(middle.r == middle.g && middle.g == middle.b, "{middle:?}");
+        assert!((blend(a, b, 0.5).a - 0.75).abs() < 1e-5);
+    }
+    
+    #[test]
+    fn blend_clamps_t() {
+        let a = Color::from_rgb(0.1, 0.4, 0.9);
+        let b = Color::from_rgb(0.9, 0.2, 0.3);
+        
+        assert_close(blend(a, b, -1.0), a);
+        assert_close(blend(a, b, 2.5), b);
+    }
+    
+    #[test]
+    fn hsl_and_hsv_round_trip() {
+        let colors = get_theme_colors(&ModernVariant::Light.theme());
+        
+        for color in [colors.blue, colors.red, colors.green, colors.orange, colors.text] {
+            let (hue, saturation, lightness) = to_hsl(color);
+            assert_close(from_hsl(hue, saturation, lightness), color);
+            
+            let (hue, saturation, value) = to_hsv(color);
+            assert_close(from_hsv(hue, saturation, value), color);
+        }
+    }
+    
+    #[test]
+    fn shift_hue_rotates_red() {
+        let red = Color::from_rgba(1.0, 0.0, 0.0, 0.5);
+        
+        assert_eq!(to_hsl(red), (0.0, 1.0, 0.5));
+        assert_close(shift_hue(red, 120.0), Color::from_rgba(0.0, 1.0, 0.0, 0.5));
+        assert_close(shift_hue(red, 240.0), Color::from_rgba(0.0, 0.0, 1.0, 0.5));
+        
+        // Negative and full turns wrap around the wheel
+        assert_close(shift_hue(red, -120.0), Color::from_rgba(0.0, 0.0, 1.0, 0.5));
+        assert_close(shift_hue(red, 360.0), red);
+    }
+}
@@ -0,0 +1,314 @@
+//! Configuration options for the Modern theme.
+//!
+//! This module provides the `ModernConfig` struct used to opt into
+//! alternative behaviors of the Modern-styled widgets.
+
+use iced::Color;
+
+use crate::colors::colors::{gray, state};
+use crate::styles::CORNER_RADIUS;
+
+/// Behavioral options for Modern-styled widgets
+///
+/// Pass a config to `Modern::with_config` to get a `ModernProvider`
+/// whose style functions honor these options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModernConfig {
+    /// Brand accent used in place of the system blue
+    ///
+    /// Pair with `Modern::themed` so the theme palette and the widget
+    /// styles share the same accent. `None` keeps the system blue.
+    pub accent: Option<Color>,
+
+    /// Corner radius of buttons, in pixels
+    ///
+    /// Text inputs, pick lists and checkboxes derive their smaller radii
+    /// from it in the same 8/6/4 proportions as the default constants.
+    pub corner_radius: f32,
+
+    /// Fill outlined buttons with their accent color on hover
+    ///
+    /// By default outlined buttons (e.g. `secondary_button`) show a subtle
+    /// accent tint on hover. When enabled they fill with the accent and
+    /// switch to the filled buttons' label color, for a stronger affordance.
+    pub outline_fill_on_hover: bool,
+
+    /// Show a green border for valid input in `conditional_text_input`
+    ///
+    /// Off by default to avoid overly noisy forms; valid input then uses
+    /// the plain inline style.
+    pub success_on_valid: bool,
+
+    /// Keep button shadows when pressed
+    ///
+    /// By default pressed buttons drop their shadow to appear pushed down.
+    /// When enabled the shadow stays and only the color darkens, for a
+    /// flat-press feel. Transparent buttons have no shadow either way.
+    pub shadow_on_press: bool,
+
+    /// Give filled buttons a faint top-to-bottom sheen
+    ///
+    /// Off by default for a flat fill. When enabled every filled button is
+    /// drawn with a gradient that is slightly lighter at the top, for a
+    /// subtle glossy depth. Hover and press still darken or lighten it.
+    pub button_sheen: bool,
+
+    /// Brighten pressed buttons instead of darkening them
+    ///
+    /// Gives touch-first apps a visible flash under the finger: the pressed
+    /// state blends the button color towards white. For a flash that outlasts
+    /// the press, animate `Modern::flash_button` yourself.
+    pub press_flash: bool,
+
+    /// Give plain and link buttons a faint background on hover
+    ///
+    /// By default these text-only buttons just fade their text. When enabled
+    /// they gain a `system_bg` highlight on hover and press, like macOS menu
+    /// items, which reads as more clickable in menus and lists.
+    pub text_button_hover_bg: bool,
+
+    /// Tint elevated surfaces towards the accent in dark mode
+    ///
+    /// Shadows barely register on dark backgrounds, so when enabled cards,
+    /// elevated containers and sheets, and floating panels blend in 5%, 8%
+    /// and 11% of the accent respectively, Material-style. Light mode keeps
+    /// using shadows only.
+    pub surface_tint: bool,
+
+    /// Mirror direction-dependent styles for right-to-left layouts
+    ///
+    /// Styles can't change layout direction, so this only flips what they do
+    /// control: in input groups the input rounds its right corners and the
+    /// button its left ones, and sidebars cast their shadow to the left. For
+    /// your own directional shadows use `ShadowDirection::mirrored`. Colors,
+    /// including the input `icon` color, stay the same. Use
+    /// `Modern::input_padding_rtl` for the matching padding.
+    pub rtl: bool,
+
+    /// Snap container, button and rule bounds to the pixel grid
+    ///
+    /// On by default for crisp edges. Turn it off for widgets that are
+    /// animated or moved by fractional amounts, where snapping makes them
+    /// jitter. Honored by the container, button and rule styles of
+    /// `ModernProvider`.
+    pub snap: bool,
+
+    /// Draw translucent backgrounds as opaque colors
+    ///
+    /// Mirrors the OS "reduce transparency" accessibility setting. When
+    /// enabled, tinted and ghost button fills and the secondary button hover
+    /// tint are composited over the theme background, so content behind them
+    /// no longer shows through.
+    pub reduce_transparency: bool,
+
+    /// Width below which `responsive_sheet_container` drops its rounding, in pixels
+    ///
+    /// Defaults to 600, roughly where phone layouts end and sheets start
+    /// spanning the full window width.
+    pub sheet_breakpoint: f32,
+
+    /// Opacity of the tinted button fill, from 0.0 to 1.0
+    ///
+    /// Defaults to 0.2. Raise it for bolder tints or lower it for subtler
+    /// ones; hover and press scale it by 1.5x and 2x so the states stay
+    /// proportionally distinct.
+    pub tint_alpha: f32,
+
+    /// Opacity of separators, from 0.0 to 1.0
+    ///
+    /// Defaults to 1.0, the theme's own separator color. Lower it for
+    /// fainter dividers; raising separator contrast is best done with
+    /// `gray_ramp` or the high contrast themes instead.
+    pub separator_alpha: f32,
+
+    /// Multiplier applied to the opacity of button and container shadows
+    ///
+    /// Defaults to 1.0, the built-in shadows. Use values above 1.0 for more
+    /// pronounced depth on busy backgrounds, or 0.0 to drop shadows entirely.
+    /// The resulting opacity is clamped to 1.0.
+    pub shadow_alpha: f32,
+
+    /// Custom neutral gray ramp used in place of the system grays
+    ///
+    /// Lets products with their own neutral palette restyle system and gray
+    /// buttons, separators, unselected borders and input backgrounds
+    /// consistently. `None` keeps each theme's own grays.
+    pub gray_ramp: Option<GrayRamp>,
+
+    /// Color temperature shift applied to every color role, in kelvin
+    ///
+    /// Negative values warm the styles for evening use, positive ones cool
+    /// them; see `shift_temperature` for the recommended range. A subtler
+    /// alternative to the sepia theme. Defaults to 0 (no shift).
+    pub warmth: f32,
+
+    /// Soften accent colors in dark mode
+    ///
+    /// On by default: dark themes slightly desaturate and lighten every
+    /// accent (buttons, links, focus borders, selection) with
+    /// `soften_dark_accent`, which reads calmer on dark surfaces. Filled
+    /// button labels stay legible on the lighter accents through
+    /// `accent_label_color`. Turn it off for the unmodified dark system
+    /// colors. High contrast themes are never softened, and a custom
+    /// `accent` is used as given.
+    pub desaturate_dark_accents: bool,
+}
+
+impl ModernConfig {
+    /// Corner radius of text inputs and pick lists
+    pub fn small_corner_radius(&self) -> f32 {
+        self.corner_radius * 0.75
+    }
+
+    /// Corner radius of checkboxes
+    pub fn tiny_corner_radius(&self) -> f32 {
+        self.corner_radius * 0.5
+    }
+}
+
+impl Default for ModernConfig {
+    fn default() -> Self {
+        Self {
+            accent: None,
+            corner_radius: CORNER_RADIUS,
+            outline_fill_on_hover: false,
+            success_on_valid: false,
+            shadow_on_press: false,
+            button_sheen: false,
+            press_flash: false,
+            text_button_hover_bg: false,
+            surface_tint: false,
+            rtl: false,
+            snap: true,
+            reduce_transparency: false,
+            sheet_breakpoint: 600.0,
+            tint_alpha: 0.2,
+            separator_alpha: 1.0,
+            shadow_alpha: 1.0,
+            gray_ramp: None,
+            warmth: 0.0,
+            desaturate_dark_accents: true,
+        }
+    }
+}
+
+/// A six-step neutral gray ramp for light and dark mode
+///
+/// Steps follow the system grays: index 0 is Gray 1, the strongest gray
+/// used for icons, and index 5 is Gray 6, the faintest, closest to the
+/// background. Colors are derived from it as follows:
+///
+/// - `inactive_border`: Gray 3 in light mode, Gray 2 in dark mode
+/// - `separator`: the ramp's own separator colors (Gray 3 with
+///   `GrayRamp::new`)
+/// - `system_bg` and gray buttons: Gray 5 and Gray 4 in light mode, Gray 4
+///   and Gray 3 in dark mode
+/// - `input_bg`: Gray 6 in light mode, Gray 5 in dark mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrayRamp {
+    /// Grays used in light mode, from Gray 1 to Gray 6
+    pub light: [Color; 6],
+    /// Grays used in dark mode, from Gray 1 to Gray 6
+    pub dark: [Color; 6],
+    /// Separator color in light mode
+    pub separator_light: Color,
+    /// Separator color in dark mode
+    pub separator_dark: Color,
+}
+
+impl GrayRamp {
+    /// Create a ramp from light and dark grays, using Gray 3 for separators
+    pub fn new(light: [Color; 6], dark: [Color; 6]) -> Self {
+        Self {
+            light,
+            dark,
+            separator_light: light[2],
+            separator_dark: dark[2],
+        }
+    }
+
+    /// The system gray ramp the Modern themes use by default
+    ///
+    /// Reproduces the default colors of the standard light and dark themes,
+    /// including the dark separator, which sits between Gray 2 and Gray 3.
+    pub fn system() -> Self {
+        Self {
+            light: [
+                gray::GRAY1_LIGHT,
+                gray::GRAY2_LIGHT,
+                gray::GRAY3_LIGHT,
+                gray::GRAY4_LIGHT,
+                gray::GRAY5_LIGHT,
+                gray::GRAY6_LIGHT,
+            ],
+            dark: [
+                gray::GRAY1_DARK,
+                gray::GRAY2_DARK,
+                gray::GRAY3_DARK,
+                gray::GRAY4_DARK,
+                gray::GRAY5_DARK,
+                gray::GRAY6_DARK,
+            ],
+            separator_light: state::SEPARATOR_LIGHT,
+            separator_dark: state::SEPARATOR_DARK,
+        }
+    }
+
+    /// The grays for light or dark mode
+    pub fn grays(&self, dark_mode: bool) -> [Color; 6] {
+        if dark_mode {
+            self.dark
+        } else {
+            self.light
+        }
+    }
+
+    /// The separator color for light or dark mode
+    pub fn separator(&self, dark_mode: bool) -> Color {
+        if dark_mode {
+            self.separator_dark
+        } else {
+            self.separator_light
+        }
+    }
+
+    /// The fill of gray buttons
+    pub fn button_gray(&self, dark_mode: bool) -> Color {
+        let grays = self.grays(dark_mode);
+
+        if dark_mode {
+            grays[2]
+        } else {
+            grays[3]
+        }
+    }
+}
+
+impl Default for GrayRamp {
+    fn default() -> Self {
+        Self::system()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors::get_theme_colors_with;
+    use crate::theme::Modern;
+
+    #[test]
+    fn system_gray_ramp_matches_default_colors() {
+        let with_ramp = ModernConfig {
+            gray_ramp: Some(GrayRamp::system()),
+            ..ModernConfig::default()
+        };
+
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            assert_eq!(
+                get_theme_colors_with(&theme, &with_ramp),
+                get_theme_colors_with(&theme, &ModernConfig::default()),
+                "{theme}"
+            );
+        }
+    }
+}
@@ -12,12 +12,20 @@
 //! - Variants for different button styles (primary, secondary, tinted, etc.)
 //! - Ready-to-use theme that can be applied to any Iced application
 
+pub mod builder;
 pub mod colors;
+pub mod config;
 pub mod styles;
 pub mod theme;
+#[cfg(feature = "serde")]
+pub mod tokens;
 
+pub use builder::*;
 pub use colors::*;
+pub use config::*;
 pub use styles::*;
+#[cfg(feature = "serde")]
+pub use tokens::*;
 
 /// Re-export the Apple struct as the main entry point for the library
-pub use theme::Modern;
\ No newline at end of file
+pub use theme::{ButtonStyles, Modern, ModernProvider};
\ No newline at end of file
@@ -3,17 +3,38 @@
 //! This module provides the style variants and enum definitions
 //! used throughout the Apple theme.
 
+use crate::theme::Modern;
+
 /// Common constants for element sizing
 pub const CORNER_RADIUS: f32 = 8.0;
 pub const SMALL_CORNER_RADIUS: f32 = 6.0;
 pub const TINY_CORNER_RADIUS: f32 = 4.0;
+/// Minimum touch target size recommended by accessibility guidelines
+pub const MIN_TOUCH_SIZE: f32 = 44.0;
+
+/// Spacing scale for padding and gaps, in multiples of 4px
+pub mod spacing {
+    /// Extra small spacing, between an icon and its label
+    pub const SPACING_XS: f32 = 4.0;
+    /// Small spacing, between related controls
+    pub const SPACING_S: f32 = 8.0;
+    /// Medium spacing, the default padding of cards and groups
+    pub const SPACING_M: f32 = 16.0;
+    /// Large spacing, between sections
+    pub const SPACING_L: f32 = 24.0;
+    /// Extra large spacing, around page content
+    pub const SPACING_XL: f32 = 32.0;
+}
 
 /// Apple-styled component variants
 pub mod style {
+    use super::TintedButtonColor;
+
     /// Button style variants
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
     pub enum Button {
         /// Primary filled button (blue)
+        #[default]
         Primary,
         /// Secondary outlined button
         Secondary,
@@ -29,15 +50,64 @@ pub mod style {
         System,
         /// Plain text button
         Plain,
+        /// Tinted button (semi-transparent colored background)
+        Tinted(TintedButtonColor),
+    }
+
+    impl Button {
+        /// Every button style variant, in declaration order
+        ///
+        /// Tinted buttons are listed once per color.
+        pub fn all() -> &'static [Self] {
+            &[
+                Self::Primary,
+                Self::Secondary,
+                Self::Success,
+                Self::Warning,
+                Self::Danger,
+                Self::Link,
+                Self::System,
+                Self::Plain,
+                Self::Tinted(TintedButtonColor::Blue),
+                Self::Tinted(TintedButtonColor::Green),
+                Self::Tinted(TintedButtonColor::Red),
+                Self::Tinted(TintedButtonColor::Orange),
+                Self::Tinted(TintedButtonColor::Purple),
+                Self::Tinted(TintedButtonColor::Teal),
+                Self::Tinted(TintedButtonColor::Pink),
+                Self::Tinted(TintedButtonColor::Indigo),
+            ]
+        }
+    }
+
+    impl std::fmt::Display for Button {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let name = match self {
+                Self::Tinted(color) => return write!(f, "{color} Tinted"),
+                Self::Primary => "Primary",
+                Self::Secondary => "Secondary",
+                Self::Success => "Success",
+                Self::Warning => "Warning",
+                Self::Danger => "Danger",
+                Self::Link => "Link",
+                Self::System => "System",
+                Self::Plain => "Plain",
+            };
+
+            f.write_str(name)
+        }
     }
 
     /// Container style variants
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
     pub enum Container {
         /// Standard transparent container
+        #[default]
         Transparent,
         /// Card style with background
         Card,
+        /// Elevated surface with a stronger shadow than Card, for popovers
+        Elevated,
         /// Sheet/modal style
         Sheet,
         /// Group style (for grouped tables/lists)
@@ -45,22 +115,10 @@ pub mod style {
         /// Sidebar style
         Sidebar,
     }
-
-    impl Default for Button {
-        fn default() -> Self {
-            Self::Primary
-        }
-    }
-
-    impl Default for Container {
-        fn default() -> Self {
-            Self::Transparent
-        }
-    }
 }
 
 /// Colors available for tinted buttons
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TintedButtonColor {
     Blue,
     Green,
@@ -72,6 +130,266 @@ pub enum TintedButtonColor {
     Indigo,
 }
 
+impl TintedButtonColor {
+    /// Every tinted button color, in declaration order
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::Blue,
+            Self::Green,
+            Self::Red,
+            Self::Orange,
+            Self::Purple,
+            Self::Teal,
+            Self::Pink,
+            Self::Indigo,
+        ]
+    }
+}
+
+impl std::fmt::Display for TintedButtonColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Blue => "Blue",
+            Self::Green => "Green",
+            Self::Red => "Red",
+            Self::Orange => "Orange",
+            Self::Purple => "Purple",
+            Self::Teal => "Teal",
+            Self::Pink => "Pink",
+            Self::Indigo => "Indigo",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// The built-in Modern themes, for storing a user's choice
+///
+/// Keep the variant in your app state (it serializes with the `serde`
+/// feature) and materialize the theme on demand with `theme()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModernVariant {
+    /// Standard light theme
+    #[default]
+    Light,
+    /// Standard dark theme
+    Dark,
+    /// Pure-black dark theme for OLED displays
+    OledDark,
+    /// Light theme with accessible accents and stronger borders
+    HighContrastLight,
+    /// Dark theme with accessible accents and stronger borders
+    HighContrastDark,
+    /// Warm, low-blue reading theme
+    Sepia,
+}
+
+impl ModernVariant {
+    /// Every theme variant, in declaration order
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::Light,
+            Self::Dark,
+            Self::OledDark,
+            Self::HighContrastLight,
+            Self::HighContrastDark,
+            Self::Sepia,
+        ]
+    }
+
+    /// Create the theme for this variant
+    pub fn theme(self) -> iced::Theme {
+        match self {
+            Self::Light => Modern::light_theme(),
+            Self::Dark => Modern::dark_theme(),
+            Self::OledDark => Modern::oled_dark_theme(),
+            Self::HighContrastLight => Modern::high_contrast_theme(false),
+            Self::HighContrastDark => Modern::high_contrast_theme(true),
+            Self::Sepia => Modern::sepia_theme(),
+        }
+    }
+}
+
+impl std::fmt::Display for ModernVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Light => "Light",
+            Self::Dark => "Dark",
+            Self::OledDark => "OLED Dark",
+            Self::HighContrastLight => "High Contrast Light",
+            Self::HighContrastDark => "High Contrast Dark",
+            Self::Sepia => "Sepia",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Built-in accent color presets, for offering a choice of accents
+///
+/// Each preset has a light and a dark mode color that both keep white
+/// button text readable. Apply one with `Modern::with_accent_preset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccentPreset {
+    /// Deep sea blue
+    #[default]
+    Ocean,
+    /// Leafy green
+    Forest,
+    /// Warm red-orange
+    Sunset,
+    /// Blue-violet
+    Grape,
+    /// Raspberry pink
+    Rose,
+    /// Neutral slate gray
+    Graphite,
+}
+
+impl AccentPreset {
+    /// Every accent preset, in declaration order
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::Ocean,
+            Self::Forest,
+            Self::Sunset,
+            Self::Grape,
+            Self::Rose,
+            Self::Graphite,
+        ]
+    }
+
+    /// The accent color of this preset in light or dark mode
+    pub fn accent(self, dark_mode: bool) -> iced::Color {
+        use iced::Color;
+
+        match (self, dark_mode) {
+            (Self::Ocean, false) => Color::from_rgb(0.0, 0.47, 0.71),     // #0077B6
+            (Self::Ocean, true) => Color::from_rgb(0.1, 0.56, 0.82),      // #1A8FD1
+            (Self::Forest, false) => Color::from_rgb(0.18, 0.49, 0.2),    // #2E7D32
+            (Self::Forest, true) => Color::from_rgb(0.23, 0.6, 0.28),     // #3A9A48
+            (Self::Sunset, false) => Color::from_rgb(0.85, 0.28, 0.06),   // #D9480F
+            (Self::Sunset, true) => Color::from_rgb(0.91, 0.35, 0.05),    // #E8590C
+            (Self::Grape, false) => Color::from_rgb(0.44, 0.28, 0.91),    // #7048E8
+            (Self::Grape, true) => Color::from_rgb(0.52, 0.37, 0.97),     // #845EF7
+            (Self::Rose, false) => Color::from_rgb(0.84, 0.2, 0.42),      // #D6336C
+            (Self::Rose, true) => Color::from_rgb(0.9, 0.29, 0.5),        // #E64980
+            (Self::Graphite, false) => Color::from_rgb(0.29, 0.31, 0.34), // #495057
+            (Self::Graphite, true) => Color::from_rgb(0.42, 0.46, 0.49),  // #6C757D
+        }
+    }
+}
+
+impl std::fmt::Display for AccentPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Ocean => "Ocean",
+            Self::Forest => "Forest",
+            Self::Sunset => "Sunset",
+            Self::Grape => "Grape",
+            Self::Rose => "Rose",
+            Self::Graphite => "Graphite",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Directions a container shadow can be cast in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShadowDirection {
+    /// Shadow falls below the element (the usual light-from-above look)
+    #[default]
+    Down,
+    /// Shadow falls above the element, for dropups and bottom-anchored panels
+    Up,
+    /// Shadow falls to the left of the element
+    Left,
+    /// Shadow falls to the right of the element
+    Right,
+}
+
+impl ShadowDirection {
+    /// The direction mirrored horizontally, for right-to-left layouts
+    ///
+    /// Swaps `Left` and `Right`; `Up` and `Down` are unchanged.
+    pub fn mirrored(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            direction => direction,
+        }
+    }
+}
+
+/// Where an image scrim is darkest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientDirection {
+    /// Darkens towards the bottom edge, for captions below the image content
+    #[default]
+    Bottom,
+    /// Darkens towards the top edge, for titles and toolbars over the image
+    Top,
+    /// Darkens the whole image evenly, for centered text on hero banners
+    Full,
+}
+
+/// Text emphasis levels, from full-strength to disabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EmphasisLevel {
+    /// Full-strength text (alpha 1.0)
+    #[default]
+    High,
+    /// Supporting text (alpha 0.7)
+    Medium,
+    /// De-emphasized hints and metadata (alpha 0.55)
+    Low,
+    /// Text of disabled content (alpha 0.4)
+    Disabled,
+}
+
+impl EmphasisLevel {
+    /// Alpha applied to the text color at this level
+    pub fn alpha(self) -> f32 {
+        match self {
+            Self::High => 1.0,
+            Self::Medium => 0.7,
+            Self::Low => 0.55,
+            Self::Disabled => 0.4,
+        }
+    }
+}
+
+/// Visual weight of a button, from the main call to action to a minor one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ButtonEmphasis {
+    /// Filled with the color and white (or dark) text
+    #[default]
+    High,
+    /// Tinted: a translucent fill with colored text
+    Medium,
+    /// Outlined in the color with a transparent fill
+    Low,
+}
+
+/// Steps of the spacing scale, from `spacing::SPACING_XS` to `spacing::SPACING_XL`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpacingLevel {
+    /// 4px
+    ExtraSmall,
+    /// 8px
+    Small,
+    /// 16px
+    #[default]
+    Medium,
+    /// 24px
+    Large,
+    /// 32px
+    ExtraLarge,
+}
+
 /// Size variants for buttons
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ButtonSize {
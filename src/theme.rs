@@ -3,17 +3,85 @@
 //! This module provides the main `Modern` struct and implementations
 //! for styling each Iced component with Modern-inspired designs.
 
+use iced::gradient::{Gradient, Linear};
 use iced::overlay::menu;
-use iced::{Background, Border, Color, Shadow, Theme, Vector};
+use iced::{border, Background, Border, Color, Font, Padding, Radians, Shadow, Theme, Vector};
+
+/// Drop shadow of filled buttons
+const BUTTON_SHADOW: Shadow = Shadow {
+    color: Color {
+        a: 0.1,
+        ..Color::BLACK
+    },
+    offset: Vector::new(0.0, 1.0),
+    blur_radius: 2.0,
+};
+
+/// Lighter drop shadow of tinted buttons
+const TINTED_BUTTON_SHADOW: Shadow = Shadow {
+    color: Color {
+        a: 0.05,
+        ..Color::BLACK
+    },
+    offset: Vector::new(0.0, 1.0),
+    blur_radius: 2.0,
+};
+
+/// Shadow of card containers
+const CARD_SHADOW: Shadow = Shadow {
+    color: Color {
+        a: 0.1,
+        ..Color::BLACK
+    },
+    offset: Vector::new(0.0, 2.0),
+    blur_radius: 8.0,
+};
+
+/// Shadow of sheet containers
+const SHEET_SHADOW: Shadow = Shadow {
+    color: Color {
+        a: 0.2,
+        ..Color::BLACK
+    },
+    offset: Vector::new(0.0, 4.0),
+    blur_radius: 16.0,
+};
+
+/// Shadow of floating panel containers
+const FLOATING_SHADOW: Shadow = Shadow {
+    color: Color {
+        a: 0.25,
+        ..Color::BLACK
+    },
+    offset: Vector::new(0.0, 4.0),
+    blur_radius: 16.0,
+};
+
+/// Shadow cast to the right by sidebar containers (to the left when mirrored for RTL)
+const SIDEBAR_SHADOW: Shadow = Shadow {
+    color: Color {
+        a: 0.05,
+        ..Color::BLACK
+    },
+    offset: Vector::new(1.0, 0.0),
+    blur_radius: 3.0,
+};
+
+/// Opacity of input placeholders while focused, to de-emphasize them once typing begins
+const FOCUSED_PLACEHOLDER_ALPHA: f32 = 0.7;
 
 /// Modern design-inspired text input style implementation
-fn text_input_style(theme: &Theme, status: TextInputStatus) -> text_input::Style {
-    let colors = get_theme_colors(theme);
+fn text_input_style(
+    theme: &Theme,
+    status: TextInputStatus,
+    config: &ModernConfig,
+) -> text_input::Style {
+    let colors = get_theme_colors_with(theme, config);
 
     let base_style = text_input::Style {
         background: Background::Color(colors.input_bg),
         border: Border {
-            radius: SMALL_CORNER_RADIUS.into(),
+            radius: config.small_corner_radius().into(),
             width: 1.0,
             color: colors.input_border,
         },
@@ -38,6 +106,8 @@ fn text_input_style(theme: &Theme, status: TextInputStatus) -> text_input::Style
                 width: 2.0,
                 ..base_style.border
             },
+            // Dim the placeholder once the input is ready for typing
+            placeholder: colors.placeholder.scale_alpha(FOCUSED_PLACEHOLDER_ALPHA),
             ..base_style
         },
         TextInputStatus::Disabled => text_input::Style {
@@ -53,8 +123,12 @@ fn text_input_style(theme: &Theme, status: TextInputStatus) -> text_input::Style
 }
 
 /// Modern design-inspired pick list style implementation
-fn pick_list_style(theme: &Theme, status: pick_list::Status) -> pick_list::Style {
-    let colors = get_theme_colors(theme);
+fn pick_list_style(
+    theme: &Theme,
+    status: pick_list::Status,
+    config: &ModernConfig,
+) -> pick_list::Style {
+    let colors = get_theme_colors_with(theme, config);
 
     // Base style
     let base_style = pick_list::Style {
@@ -62,7 +136,7 @@ fn pick_list_style(theme: &Theme, status: pick_list::Status) -> pick_list::Style
         placeholder_color: colors.placeholder,
         background: Background::Color(colors.input_bg),
         border: Border {
-            radius: SMALL_CORNER_RADIUS.into(),
+            radius: config.small_corner_radius().into(),
             width: 1.0,
             color: colors.input_border,
         },
@@ -84,6 +158,8 @@ fn pick_list_style(theme: &Theme, status: pick_list::Status) -> pick_list::Style
                 width: 1.5,
                 ..base_style.border
             },
+            // Keep the value at full strength and recede the placeholder, like focused inputs
+            placeholder_color: colors.placeholder.scale_alpha(FOCUSED_PLACEHOLDER_ALPHA),
             handle_color: colors.blue,
             ..base_style
         },
@@ -93,17 +169,23 @@ fn pick_list_style(theme: &Theme, status: pick_list::Status) -> pick_list::Style
 /// Modern design-inspired combo box style implementation
 fn combo_box_style(theme: &Theme, status: TextInputStatus) -> text_input::Style {
     // For consistency, we use the same style as text input
-    text_input_style(theme, status)
+    text_input_style(theme, status, &ModernConfig::default())
 }
 
-/// Create a complete Modern-styled theme
-fn create_modern_theme(dark_mode: bool) -> Theme {
+/// Create a complete Modern-styled theme, optionally with a custom primary accent
+fn create_modern_theme(dark_mode: bool, accent: Option<Color>) -> Theme {
     let name = if dark_mode {
         "Modern Dark"
     } else {
         "Modern Light"
     };
 
+    // Create the Modern theme
+    Theme::custom(String::from(name), modern_palette(dark_mode, accent))
+}
+
+/// The palette of the standard Modern themes, optionally with a brand accent
+fn modern_palette(dark_mode: bool, accent: Option<Color>) -> iced::theme::Palette {
     // Define the base colors
     let (background, text) = if dark_mode {
         (Color::from_rgb(0.11, 0.11, 0.12), Color::WHITE) // #1C1C1E (dark bg)
@@ -111,11 +193,11 @@ fn create_modern_theme(dark_mode: bool) -> Theme {
         (Color::from_rgb(0.95, 0.95, 0.97), Color::BLACK) // #F2F2F7 (light bg)
     };
 
-    let primary = if dark_mode {
+    let primary = accent.unwrap_or(if dark_mode {
         MODERN_BLUE_DARK
     } else {
         MODERN_BLUE_LIGHT
-    };
+    });
     let success = if dark_mode {
         MODERN_GREEN_DARK
     } else {
@@ -132,23 +214,72 @@ fn create_modern_theme(dark_mode: bool) -> Theme {
         MODERN_ORANGE_LIGHT
     };
 
-    // Create the Modern theme
-    Theme::custom(
-        String::from(name),
+    iced::theme::Palette {
+        background,
+        text,
+        primary,
+        success,
+        danger,
+        warning,
+    }
+}
+
+/// Create a high-contrast Modern theme using the accessible accent colors
+fn create_high_contrast_theme(dark_mode: bool) -> Theme {
+    let name = if dark_mode {
+        "Modern High Contrast Dark"
+    } else {
+        "Modern High Contrast Light"
+    };
+
+    let palette = if dark_mode {
+        iced::theme::Palette {
+            background: Color::BLACK,
+            text: Color::WHITE,
+            primary: colors::accessibility::BLUE_DARK,
+            success: colors::accessibility::GREEN_DARK,
+            danger: colors::accessibility::RED_DARK,
+            warning: colors::accessibility::ORANGE_DARK,
+        }
+    } else {
         iced::theme::Palette {
-            background,
-            text,
-            primary,
-            success,
-            danger,
-            warning,
+            background: Color::WHITE,
+            text: Color::BLACK,
+            primary: colors::accessibility::BLUE_LIGHT,
+            success: colors::accessibility::GREEN_LIGHT,
+            danger: colors::accessibility::RED_LIGHT,
+            warning: colors::accessibility::ORANGE_LIGHT,
+        }
+    };
+
+    Theme::custom(String::from(name), palette)
+}
+
+/// Modern design-inspired dropdown menu style with the given selection colors
+fn menu_style(
+    theme: &Theme,
+    selected_background: Color,
+    selected_text_color: Color,
+) -> menu::Style {
+    let colors = get_theme_colors(theme);
+
+    menu::Style {
+        text_color: colors.text,
+        background: Background::Color(colors.card_bg),
+        border: Border {
+            radius: TINY_CORNER_RADIUS.into(),
+            width: 1.0,
+            color: colors.input_border,
         },
-    )
+        selected_text_color,
+        selected_background: Background::Color(selected_background),
+        shadow: Shadow::default(),
+    }
 }
 
 /// Modern design-inspired radio button style implementation
-fn radio_style(theme: &Theme, status: radio::Status) -> radio::Style {
-    let colors = get_theme_colors(theme);
+fn radio_style(theme: &Theme, status: radio::Status, config: &ModernConfig) -> radio::Style {
+    let colors = get_theme_colors_with(theme, config);
 
     // Base style
     let style = radio::Style {
@@ -175,17 +306,46 @@ fn radio_style(theme: &Theme, status: radio::Status) -> radio::Style {
 }
 
 /// Modern design-inspired checkbox style implementation
-fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
-    let colors = get_theme_colors(theme);
+fn checkbox_style(
+    theme: &Theme,
+    status: checkbox::Status,
+    config: &ModernConfig,
+) -> checkbox::Style {
+    let colors = get_theme_colors_with(theme, config);
+
+    colored_checkbox_style(theme, status, colors.blue, config)
+}
+
+/// Pick a legible check mark color for a checkbox fill
+///
+/// White reads best on the saturated accents, so it is kept unless it falls
+/// below the large-element AA ratio; light fills (e.g. yellow) get dark text.
+fn checkbox_icon_color(fill: Color) -> Color {
+    if contrast_ratio(Color::WHITE, fill) >= CONTRAST_AA_LARGE {
+        Color::WHITE
+    } else {
+        on_color(fill)
+    }
+}
+
+/// Checkbox style filled with an arbitrary color when checked
+fn colored_checkbox_style(
+    theme: &Theme,
+    status: checkbox::Status,
+    fill: Color,
+    config: &ModernConfig,
+) -> checkbox::Style {
+    let colors = get_theme_colors_with(theme, config);
+    let icon_color = checkbox_icon_color(fill);
 
     match status {
         checkbox::Status::Active { is_checked } => {
             if is_checked {
                 checkbox::Style {
-                    background: Background::Color(colors.blue),
-                    icon_color: Color::WHITE,
+                    background: Background::Color(fill),
+                    icon_color,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: config.tiny_corner_radius().into(),
                         width: 0.0,
                         color: Color::TRANSPARENT,
                     },
@@ -196,7 +356,7 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
                     background: Background::Color(Color::TRANSPARENT),
                     icon_color: Color::TRANSPARENT,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: config.tiny_corner_radius().into(),
                         width: 2.0,
                         color: colors.inactive_border,
                     },
@@ -207,10 +367,10 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
         checkbox::Status::Hovered { is_checked } => {
             if is_checked {
                 checkbox::Style {
-                    background: Background::Color(colors.blue.scale_alpha(0.9)),
-                    icon_color: Color::WHITE,
+                    background: Background::Color(fill.scale_alpha(0.9)),
+                    icon_color,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: config.tiny_corner_radius().into(),
                         width: 0.0,
                         color: Color::TRANSPARENT,
                     },
@@ -221,9 +381,9 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
                     background: Background::Color(Color::TRANSPARENT),
                     icon_color: Color::TRANSPARENT,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: config.tiny_corner_radius().into(),
                         width: 2.0,
-                        color: colors.blue.scale_alpha(0.5),
+                        color: fill.scale_alpha(0.5),
                     },
                     text_color: Some(colors.text),
                 }
@@ -232,10 +392,10 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
         checkbox::Status::Disabled { is_checked } => {
             if is_checked {
                 checkbox::Style {
-                    background: Background::Color(colors.blue.scale_alpha(0.5)),
-                    icon_color: Color::WHITE.scale_alpha(0.5),
+                    background: Background::Color(fill.scale_alpha(0.5)),
+                    icon_color: icon_color.scale_alpha(0.5),
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: config.tiny_corner_radius().into(),
                         width: 0.0,
                         color: Color::TRANSPARENT,
                     },
@@ -246,7 +406,7 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
                     background: Background::Color(Color::TRANSPARENT),
                     icon_color: Color::TRANSPARENT,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: config.tiny_corner_radius().into(),
                         width: 2.0,
                         color: colors.inactive_border.scale_alpha(0.5),
                     },
@@ -257,9 +417,101 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
     }
 }
 
+/// Blend the accent into an elevated surface in dark mode when `surface_tint` is enabled
+///
+/// `amount` grows with elevation, following Material's tonal elevation.
+fn tint_surface(theme: &Theme, background: Color, amount: f32, config: &ModernConfig) -> Color {
+    if !config.surface_tint || !is_dark_mode(theme) {
+        return background;
+    }
+
+    let accent = get_theme_colors_with(theme, config).blue;
+
+    Color {
+        a: background.a,
+        ..blend(background, accent, amount)
+    }
+}
+
+/// Composite a translucent color over the theme background when `reduce_transparency` is enabled
+fn solidify(theme: &Theme, color: Color, config: &ModernConfig) -> Color {
+    if !config.reduce_transparency || color.a >= 1.0 {
+        return color;
+    }
+
+    let backdrop = theme.palette().background;
+
+    Color {
+        a: 1.0,
+        ..blend(backdrop, color, color.a)
+    }
+}
+
+/// Minimum contrast between a link and its pressed color, so the press reads clearly
+const PRESSED_LINK_CONTRAST: f32 = 1.25;
+
+/// Deepen a link color for the pressed state by blending it towards the text color
+///
+/// Reducing alpha alone washes links out; this keeps them saturated while
+/// making the press visible, darker in light mode and lighter in dark mode.
+fn pressed_link_color(colors: &ThemeColors) -> Color {
+    let mut pressed = blend(colors.link, colors.text, 0.3);
+
+    // Where the text is about as light as the link (as in sepia) the blend
+    // barely shows, so keep going towards black or white on the text's side
+    if contrast_ratio(pressed, colors.link) < PRESSED_LINK_CONTRAST {
+        let extreme = if relative_luminance(colors.text) < relative_luminance(colors.link) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        };
+        pressed = blend(pressed, extreme, 0.3);
+    }
+
+    Color {
+        a: colors.link.a,
+        ..pressed
+    }
+}
+
+/// Border and background colors of a file drop zone
+fn drop_zone_colors(colors: &ThemeColors, active: bool) -> (Color, Color) {
+    if active {
+        (colors.blue, colors.blue.scale_alpha(0.08))
+    } else {
+        (colors.separator, Color::TRANSPARENT)
+    }
+}
+
+/// Modern design-inspired floating panel container style
+fn floating_container_style(theme: &Theme, config: &ModernConfig) -> container::Style {
+    let colors = get_theme_colors_with(theme, config);
+
+    container::Style {
+        text_color: Some(colors.text),
+        background: Some(Background::Color(tint_surface(
+            theme,
+            colors.card_bg,
+            0.11,
+            config,
+        ))),
+        border: Border {
+            radius: 10.0.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        shadow: scaled_shadow(FLOATING_SHADOW, config),
+        snap: config.snap,
+    }
+}
+
 /// Modern design-inspired container style
-fn container_style(theme: &Theme, class: &style::Container) -> container::Style {
-    let colors = get_theme_colors(theme);
+fn container_style(
+    theme: &Theme,
+    class: &style::Container,
+    config: &ModernConfig,
+) -> container::Style {
+    let colors = get_theme_colors_with(theme, config);
 
     match class {
         style::Container::Transparent => container::Style {
@@ -267,105 +519,146 @@ fn container_style(theme: &Theme, class: &style::Container) -> container::Style
             background: None,
             border: Border::default(),
             shadow: Shadow::default(),
-            snap: true,
+            snap: config.snap,
         },
 
         style::Container::Card => {
             container::Style {
                 text_color: Some(colors.text),
-                background: Some(Background::Color(colors.card_bg)),
+                background: Some(Background::Color(tint_surface(
+                    theme,
+                    colors.card_bg,
+                    0.05,
+                    config,
+                ))),
                 border: Border {
                     radius: 10.0.into(), // Modern rounded card corners
                     width: 0.0,
                     color: Color::TRANSPARENT,
                 },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.1,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 2.0),
-                    blur_radius: 8.0,
-                },
-                snap: true,
+                shadow: scaled_shadow(CARD_SHADOW, config),
+                snap: config.snap,
             }
         }
 
-        style::Container::Sheet => {
-            let sheet_bg = if is_dark_mode(theme) {
-                Color::from_rgb(0.22, 0.22, 0.23) // #383839 (dark mode sheet)
-            } else {
-                Color::from_rgb(0.95, 0.95, 0.97) // #F2F2F7 (light mode sheet)
-            };
+        style::Container::Elevated => container::Style {
+            text_color: Some(colors.text),
+            background: Some(Background::Color(tint_surface(
+                theme,
+                colors.card_bg,
+                0.08,
+                config,
+            ))),
+            border: Border {
+                radius: 10.0.into(),
+                width: 0.0,
+                color: Color::TRANSPARENT,
+            },
+            shadow: scaled_shadow(Modern::elevation_shadow(1.5, ShadowDirection::Down), config),
+            snap: config.snap,
+        },
 
+        style::Container::Sheet => {
             container::Style {
                 text_color: Some(colors.text),
-                background: Some(Background::Color(sheet_bg)),
+                background: Some(Background::Color(tint_surface(
+                    theme,
+                    colors.surface_elevated,
+                    0.08,
+                    config,
+                ))),
                 border: Border {
                     radius: 12.0.into(), // Modern rounded sheet corners
                     width: 0.0,
                     color: Color::TRANSPARENT,
                 },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.2,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 4.0),
-                    blur_radius: 16.0,
-                },
-                snap: true,
+                shadow: scaled_shadow(SHEET_SHADOW, config),
+                snap: config.snap,
             }
         }
 
         style::Container::Group => {
-            let group_bg = if is_dark_mode(theme) {
-                Color::from_rgb(0.17, 0.17, 0.18) // #2C2C2E (dark mode group)
-            } else {
-                Color::from_rgb(0.95, 0.95, 0.97) // #F2F2F7 (light mode group)
-            };
-
             container::Style {
                 text_color: Some(colors.text),
-                background: Some(Background::Color(group_bg)),
+                background: Some(Background::Color(colors.surface)),
                 border: Border {
                     radius: 10.0.into(),
                     width: 0.0,
                     color: Color::TRANSPARENT,
                 },
                 shadow: Shadow::default(), // No shadow for groups
-                snap: true,
+                snap: config.snap,
             }
         }
 
-        style::Container::Sidebar => {
-            let sidebar_bg = if is_dark_mode(theme) {
-                Color::from_rgb(0.15, 0.15, 0.16) // #262628 (dark mode sidebar)
-            } else {
-                Color::from_rgb(0.92, 0.92, 0.93) // #EAEAEE (light mode sidebar)
-            };
-
-            container::Style {
-                text_color: Some(colors.text),
-                background: Some(Background::Color(sidebar_bg)),
-                border: Border::default(),
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.05,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(1.0, 0.0),
-                    blur_radius: 3.0,
+        style::Container::Sidebar => container::Style {
+            text_color: Some(colors.text),
+            background: Some(Background::Color(sidebar_background(theme, &colors))),
+            border: Border::default(),
+            shadow: scaled_shadow(
+                if config.rtl {
+                    Shadow {
+                        offset: Vector::new(-SIDEBAR_SHADOW.offset.x, SIDEBAR_SHADOW.offset.y),
+                        ..SIDEBAR_SHADOW
+                    }
+                } else {
+                    SIDEBAR_SHADOW
                 },
-                snap: true,
-            }
-        }
+                config,
+            ),
+            snap: config.snap,
+        },
+    }
+}
+
+/// Scale a shadow's opacity by `ModernConfig::shadow_alpha`
+fn scaled_shadow(shadow: Shadow, config: &ModernConfig) -> Shadow {
+    Shadow {
+        color: Color {
+            a: (shadow.color.a * config.shadow_alpha.max(0.0)).min(1.0),
+            ..shadow.color
+        },
+        ..shadow
+    }
+}
+
+/// Background of sidebar containers
+fn sidebar_background(theme: &Theme, colors: &ThemeColors) -> Color {
+    if is_oled(theme) {
+        colors::oled::SIDEBAR_BACKGROUND
+    } else if is_sepia(theme) {
+        // A shade of the paper rather than the cold system gray
+        colors.secondary_background
+    } else if is_dark_mode(theme) {
+        Color::from_rgb(0.15, 0.15, 0.16) // #262628 (dark mode sidebar)
+    } else {
+        Color::from_rgb(0.92, 0.92, 0.93) // #EAEAEE (light mode sidebar)
+    }
+}
+
+/// Resolve a tinted color variant against the theme colors
+fn tinted_color(colors: &ThemeColors, color: TintedButtonColor) -> Color {
+    match color {
+        TintedButtonColor::Blue => colors.blue,
+        TintedButtonColor::Green => colors.green,
+        TintedButtonColor::Red => colors.red,
+        TintedButtonColor::Orange => colors.orange,
+        TintedButtonColor::Purple => colors.purple,
+        TintedButtonColor::Teal => colors.teal,
+        TintedButtonColor::Pink => colors.pink,
+        TintedButtonColor::Indigo => colors.indigo,
     }
 }
 
 fn button_hover_style(base_style: button::Style, is_dark: bool) -> button::Style {
     let adjust_color = |color: Color| -> Color {
-        if is_dark {
+        if color.a < 1.0 {
+            // Nudging a translucent fill barely shows, so strengthen it instead
+            Color {
+                a: (color.a * 1.5).min(1.0),
+                ..color
+            }
+        } else if is_dark {
             // Lighten in dark mode
             Color {
                 r: (color.r + 0.05).min(1.0),
@@ -394,9 +687,19 @@ fn button_hover_style(base_style: button::Style, is_dark: bool) -> button::Style
     }
 }
 
-fn button_pressed_style(base_style: button::Style, is_dark: bool) -> button::Style {
+fn button_pressed_style(
+    base_style: button::Style,
+    is_dark: bool,
+    config: &ModernConfig,
+) -> button::Style {
     let adjust_color = |color: Color| -> Color {
-        if is_dark {
+        if color.a < 1.0 {
+            // Nudging a translucent fill barely shows, so strengthen it instead
+            Color {
+                a: (color.a * 2.0).min(1.0),
+                ..color
+            }
+        } else if is_dark {
             // Lighten more in dark mode
             Color {
                 r: (color.r + 0.1).min(1.0),
@@ -416,20 +719,91 @@ fn button_pressed_style(base_style: button::Style, is_dark: bool) -> button::Sty
     };
 
     let mut pressed_style = base_style;
-    pressed_style.shadow = Shadow::default(); // Remove shadow when pressed
+    if !config.shadow_on_press {
+        pressed_style.shadow = Shadow::default(); // Remove shadow when pressed
+    }
 
-    if let Some(Background::Color(color)) = base_style.background {
-        pressed_style.background = Some(Background::Color(adjust_color(color)));
+    if config.press_flash {
+        return button_flash_style(pressed_style, PRESS_FLASH_INTENSITY);
     }
 
+    pressed_style.background = base_style
+        .background
+        .map(|background| map_background(background, adjust_color));
+
     pressed_style
 }
 
+/// Apply a color adjustment to a background, including every gradient stop
+///
+/// Gradients then darken or lighten as a whole.
+fn map_background(background: Background, adjust: impl Fn(Color) -> Color) -> Background {
+    match background {
+        Background::Color(color) => Background::Color(adjust(color)),
+        Background::Gradient(Gradient::Linear(mut linear)) => {
+            for stop in linear.stops.iter_mut().flatten() {
+                stop.color = adjust(stop.color);
+            }
+            Background::Gradient(linear.into())
+        }
+    }
+}
+
+/// Fill a button with a faint top-to-bottom sheen, slightly lighter at the top
+fn sheen(color: Color) -> Background {
+    let highlight = Color {
+        a: color.a,
+        ..blend(color, Color::WHITE, 0.15)
+    };
+
+    // An angle of zero runs from the bottom edge to the top edge
+    Background::Gradient(
+        Linear::new(Radians(0.0))
+            .add_stop(0.0, color)
+            .add_stop(1.0, highlight)
+            .into(),
+    )
+}
+
+/// How far pressed buttons are brightened when `ModernConfig::press_flash` is set
+const PRESS_FLASH_INTENSITY: f32 = 0.35;
+
+/// Brighten a button towards white by `intensity` (0.0 to 1.0)
+///
+/// Text-only buttons have nothing to brighten, so they get a faint overlay
+/// of their text color instead.
+fn button_flash_style(base_style: button::Style, intensity: f32) -> button::Style {
+    let intensity = intensity.clamp(0.0, 1.0);
+
+    let flash_color = |color: Color| -> Color {
+        Color {
+            a: color.a,
+            ..blend(color, Color::WHITE, intensity)
+        }
+    };
+
+    let background = match base_style.background {
+        Some(Background::Color(color)) if color.a > 0.0 => Background::Color(flash_color(color)),
+        Some(Background::Gradient(Gradient::Linear(mut linear))) => {
+            for stop in linear.stops.iter_mut().flatten() {
+                stop.color = flash_color(stop.color);
+            }
+            Background::Gradient(linear.into())
+        }
+        _ => Background::Color(base_style.text_color.scale_alpha(0.2 * intensity)),
+    };
+
+    button::Style {
+        background: Some(background),
+        ..base_style
+    }
+}
+
 fn button_disabled_style(base_style: button::Style) -> button::Style {
     button::Style {
         background: base_style.background.map(|bg| match bg {
             Background::Color(color) => Background::Color(color.scale_alpha(0.5)),
-            _ => bg,
+            Background::Gradient(gradient) => Background::Gradient(gradient.scale_alpha(0.5)),
         }),
         text_color: base_style.text_color.scale_alpha(0.5),
         border: Border {
@@ -437,656 +811,2851 @@ fn button_disabled_style(base_style: button::Style) -> button::Style {
             ..base_style.border
         },
         shadow: Shadow::default(), // No shadow for disabled buttons
-        snap: true,
+        snap: base_style.snap,
+    }
+}
+
+/// Style of a button filled with a solid accent, with the usual status shading
+fn accent_fill_button_style(
+    theme: &Theme,
+    status: ButtonStatus,
+    color: Color,
+    text_color: Color,
+    config: &ModernConfig,
+) -> button::Style {
+    let is_dark = is_dark_mode(theme);
+
+    let base_style = button::Style {
+        background: Some(Background::Color(color)),
+        text_color,
+        border: Border {
+            radius: config.corner_radius.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        shadow: scaled_shadow(BUTTON_SHADOW, config),
+        snap: config.snap,
+    };
+
+    match status {
+        ButtonStatus::Active => base_style,
+        ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
+        ButtonStatus::Pressed => button_pressed_style(base_style, is_dark, config),
+        ButtonStatus::Disabled => button_disabled_style(base_style),
+    }
+}
+
+/// Style of a rule drawn in a single solid color across its full length
+fn solid_rule_style(color: Color, config: &ModernConfig) -> rule::Style {
+    rule::Style {
+        color,
+        radius: 0.0.into(),
+        fill_mode: rule::FillMode::Full,
+        snap: config.snap,
     }
 }
 
 use iced::widget::button::Status as ButtonStatus;
 use iced::widget::text_input::Status as TextInputStatus;
-use iced::widget::{button, checkbox, container, pick_list, radio, text, text_input};
+use iced::widget::{
+    button, checkbox, container, pick_list, progress_bar, radio, rule, scrollable, text, text_input,
+};
 
 use crate::colors::*;
+use crate::config::ModernConfig;
 use crate::styles::*;
 
 /// Modern theme utilities for styling iced widgets
 pub struct Modern;
 
-impl Modern {
-    /// Get an Modern-style theme for buttons
-    pub fn button<'a>(style: style::Button) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| button_style(theme, &style, status)
-    }
+/// Modern theme utilities bound to a specific `ModernConfig`
+///
+/// Obtained through `Modern::with_config`. Style functions returned by a
+/// provider honor the options of its config.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ModernProvider {
+    config: ModernConfig,
+}
 
-    /// Get an Modern-style theme for primary buttons (blue)
-    pub fn primary_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::button(style::Button::Primary)
+impl ModernProvider {
+    /// Create a provider for the given config
+    pub fn new(config: ModernConfig) -> Self {
+        Self { config }
     }
 
-    /// Get an Modern-style theme for secondary buttons (outlined)
-    pub fn secondary_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::button(style::Button::Secondary)
+    /// Get the config used by this provider
+    pub fn config(&self) -> ModernConfig {
+        self.config
     }
 
-    /// Get an Modern-style theme for success buttons (green)
-    pub fn success_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::button(style::Button::Success)
+    /// Get an Modern-style theme for buttons honoring this provider's config
+    pub fn button<'a>(
+        &self,
+        style: style::Button,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| button_style(theme, &style, status, &config)
     }
 
-    /// Get an Modern-style theme for warning buttons (orange)
-    pub fn warning_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::button(style::Button::Warning)
+    /// Get an Modern-style theme for primary buttons (accent filled)
+    pub fn primary_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        self.button(style::Button::Primary)
     }
 
-    /// Get an Modern-style theme for danger buttons (red)
-    pub fn danger_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::button(style::Button::Danger)
+    /// Get an Modern-style theme for secondary buttons (outlined)
+    pub fn secondary_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        self.button(style::Button::Secondary)
     }
 
     /// Get an Modern-style theme for link buttons (text-only)
-    pub fn link_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::button(style::Button::Link)
-    }
-
-    /// Get an Modern-style theme for system buttons (light gray)
-    pub fn system_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::button(style::Button::System)
-    }
-
-    /// Get an Modern-style theme for plain buttons (text only without link color)
-    pub fn plain_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::button(style::Button::Plain)
+    pub fn link_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        self.button(style::Button::Link)
     }
 
-    /// Get an Modern-style theme for text inputs
-    pub fn text_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
-        text_input_style
-    }
+    /// Get a press-and-hold danger button style honoring this provider's config
+    ///
+    /// The first `fill` (0.0 to 1.0) of the button, from the left, is drawn
+    /// one pressed step deeper than the rest.
+    pub fn danger_button_progress<'a>(
+        &self,
+        fill: f32,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+            let is_dark = is_dark_mode(theme);
+            let fill = fill.clamp(0.0, 1.0);
 
-    /// Get an Modern-style theme for containers
-    pub fn container<'a>(style: style::Container) -> impl Fn(&Theme) -> container::Style + 'a {
-        move |theme| container_style(theme, &style)
-    }
+            let solid = |color: Color| button::Style {
+                background: Some(Background::Color(color)),
+                ..button_style(theme, &style::Button::Danger, ButtonStatus::Active, &config)
+            };
+            let color_of = |style: button::Style| match style.background {
+                Some(Background::Color(color)) => color,
+                _ => colors.red,
+            };
 
-    /// Get an Modern-style theme for card containers
-    pub fn card_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        Self::container(style::Container::Card)
-    }
+            // The rest of the button follows the status, and progress is drawn
+            // one pressed step deeper so it stays visible while held down
+            let status_style = match status {
+                ButtonStatus::Hovered => button_hover_style(solid(colors.red), is_dark),
+                ButtonStatus::Pressed => button_pressed_style(solid(colors.red), is_dark, &config),
+                ButtonStatus::Active | ButtonStatus::Disabled => solid(colors.red),
+            };
+            let rest_color = color_of(status_style);
+            let progress_color =
+                color_of(button_pressed_style(solid(rest_color), is_dark, &config));
+
+            let background = if fill <= 0.0 {
+                Background::Color(rest_color)
+            } else if fill >= 1.0 {
+                Background::Color(progress_color)
+            } else {
+                // Hard stop at `fill`, running left to right
+                Background::Gradient(
+                    Linear::new(Radians(std::f32::consts::FRAC_PI_2))
+                        .add_stop(0.0, progress_color)
+                        .add_stop(fill, progress_color)
+                        .add_stop((fill + 0.001).min(1.0), rest_color)
+                        .add_stop(1.0, rest_color)
+                        .into(),
+                )
+            };
 
-    /// Get an Modern-style theme for sheet containers
-    pub fn sheet_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        Self::container(style::Container::Sheet)
-    }
+            let style = button::Style {
+                background: Some(background),
+                ..status_style
+            };
 
-    /// Get an Modern-style theme for group containers
-    pub fn group_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        Self::container(style::Container::Group)
+            if status == ButtonStatus::Disabled {
+                button_disabled_style(style)
+            } else {
+                style
+            }
+        }
     }
 
-    /// Get an Modern-style theme for sidebar containers
-    pub fn sidebar_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        Self::container(style::Container::Sidebar)
-    }
+    /// Get an Modern-style gray button honoring this provider's config
+    ///
+    /// Filled from `ModernConfig::gray_ramp` when one is set.
+    pub fn gray_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+            let is_dark = is_dark_mode(theme);
 
-    /// Get an Modern-style theme for radio buttons
-    pub fn radio<'a>() -> impl Fn(&Theme, radio::Status) -> radio::Style + 'a {
-        radio_style
-    }
+            // Gray color varies by theme
+            let gray_color = config.gray_ramp.unwrap_or_default().button_gray(is_dark);
 
-    /// Get an Modern-style theme for checkboxes
-    pub fn checkbox<'a>() -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a {
-        checkbox_style
-    }
+            let base_style = button::Style {
+                background: Some(Background::Color(gray_color)),
+                text_color: colors.text,
+                border: Border {
+                    radius: config.corner_radius.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: scaled_shadow(BUTTON_SHADOW, &config),
+                snap: config.snap,
+            };
 
-    /// Get an Modern-style theme for pick lists
-    pub fn pick_list<'a>() -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
-        pick_list_style
+            match status {
+                ButtonStatus::Active => base_style,
+                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
+                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark, &config),
+                ButtonStatus::Disabled => button_disabled_style(base_style),
+            }
+        }
     }
 
-    /*     /// Get an Modern-style theme for combo boxes
-    pub fn combo_box<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
-        combo_box_style
-    } */
+    /// Get an Modern-style ghost button honoring this provider's config
+    pub fn ghost_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+            let fill = |color: Color| Some(Background::Color(solidify(theme, color, &config)));
 
-    /// Create a complete Modern-styled theme
-    pub fn theme(dark_mode: bool) -> Theme {
-        create_modern_theme(dark_mode)
+            let base_style = button::Style {
+                background: fill(colors.fill_tertiary),
+                text_color: colors.text,
+                border: Border {
+                    radius: config.corner_radius.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
+                snap: config.snap,
+            };
+
+            match status {
+                ButtonStatus::Active => base_style,
+                ButtonStatus::Hovered => button::Style {
+                    background: fill(colors.fill_secondary),
+                    ..base_style
+                },
+                ButtonStatus::Pressed => button::Style {
+                    background: fill(colors.fill_primary),
+                    ..base_style
+                },
+                ButtonStatus::Disabled => button_disabled_style(base_style),
+            }
+        }
     }
 
-    /// Create a light Modern-styled theme
-    pub fn light_theme() -> Theme {
-        Self::theme(false)
+    /// Get a teal button style honoring this provider's config
+    pub fn teal_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+            accent_fill_button_style(
+                theme,
+                status,
+                colors.teal,
+                accent_label_color(colors.teal),
+                &config,
+            )
+        }
     }
 
-    /// Create a dark Modern-styled theme
-    pub fn dark_theme() -> Theme {
-        Self::theme(true)
+    /// Get an indigo button style honoring this provider's config
+    pub fn indigo_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+            accent_fill_button_style(
+                theme,
+                status,
+                colors.indigo,
+                accent_label_color(colors.indigo),
+                &config,
+            )
+        }
     }
 
-    // Additional button styles using more Modern colors
+    /// Get a purple button style honoring this provider's config
+    pub fn purple_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+            accent_fill_button_style(
+                theme,
+                status,
+                colors.purple,
+                accent_label_color(colors.purple),
+                &config,
+            )
+        }
+    }
 
-    /// Get a teal button style (cyan-blue)
-    pub fn teal_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+    /// Get a pink button style honoring this provider's config
+    pub fn pink_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
         move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
+            let colors = get_theme_colors_with(theme, &config);
+            accent_fill_button_style(
+                theme,
+                status,
+                colors.pink,
+                accent_label_color(colors.pink),
+                &config,
+            )
+        }
+    }
 
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
+    /// Get an amber button style honoring this provider's config
+    pub fn amber_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+            accent_fill_button_style(theme, status, colors.amber, on_color(colors.amber), &config)
+        }
+    }
+
+    /// Get a tab-bar button style honoring this provider's config
+    pub fn tab_button<'a>(
+        &self,
+        active: bool,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            let base_style = button::Style {
+                background: Some(Background::Color(Color::TRANSPARENT)),
+                text_color: if active { colors.blue } else { colors.text },
                 border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.1,
-                        ..Color::BLACK
+                    radius: config.tiny_corner_radius().into(),
+                    width: if active { 2.0 } else { 0.0 },
+                    color: if active {
+                        colors.blue
+                    } else {
+                        Color::TRANSPARENT
                     },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
                 },
-                snap: true,
+                shadow: Shadow::default(),
+                snap: config.snap,
             };
 
-            let base_style = modern_base(colors.teal, Color::WHITE);
-
             match status {
                 ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
+                ButtonStatus::Hovered => button::Style {
+                    // Tint the text towards the accent
+                    text_color: colors.blue.scale_alpha(0.8),
+                    ..base_style
+                },
+                ButtonStatus::Pressed => button::Style {
+                    text_color: colors.blue.scale_alpha(0.6),
+                    ..base_style
+                },
                 ButtonStatus::Disabled => button_disabled_style(base_style),
             }
         }
     }
 
-    /// Get an indigo button style (blue-purple)
-    pub fn indigo_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+    /// Get a sidebar navigation item button style honoring this provider's config
+    pub fn sidebar_item_button<'a>(
+        &self,
+        selected: bool,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
         move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
+            let colors = get_theme_colors_with(theme, &config);
 
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
+            // Accent fill strength for active, hovered and pressed states
+            let (active_alpha, hover_alpha, pressed_alpha) = if selected {
+                (0.15, 0.2, 0.25)
+            } else {
+                (0.0, 0.08, 0.12)
+            };
+
+            let base_style = button::Style {
+                background: Some(Background::Color(colors.blue.scale_alpha(active_alpha))),
+                text_color: if selected { colors.blue } else { colors.text },
                 border: Border {
-                    radius: CORNER_RADIUS.into(),
+                    radius: config.small_corner_radius().into(),
                     width: 0.0,
                     color: Color::TRANSPARENT,
                 },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.1,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-                snap: true,
+                shadow: Shadow::default(),
+                snap: config.snap,
             };
 
-            let base_style = modern_base(colors.indigo, Color::WHITE);
-
             match status {
                 ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
+                ButtonStatus::Hovered => button::Style {
+                    background: Some(Background::Color(colors.blue.scale_alpha(hover_alpha))),
+                    ..base_style
+                },
+                ButtonStatus::Pressed => button::Style {
+                    background: Some(Background::Color(colors.blue.scale_alpha(pressed_alpha))),
+                    ..base_style
+                },
                 ButtonStatus::Disabled => button_disabled_style(base_style),
             }
         }
     }
 
-    /// Get a purple button style
-    pub fn purple_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+    /// Get a clear icon button style for search inputs honoring this provider's config
+    pub fn clear_icon_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
         move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
+            let colors = get_theme_colors_with(theme, &config);
 
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
+            let text_color = match status {
+                ButtonStatus::Active => colors.placeholder,
+                ButtonStatus::Hovered => colors.text,
+                ButtonStatus::Pressed => colors.text.scale_alpha(0.7),
+                ButtonStatus::Disabled => colors.placeholder.scale_alpha(0.5),
+            };
+
+            button::Style {
+                background: None,
                 text_color,
                 border: Border {
-                    radius: CORNER_RADIUS.into(),
+                    radius: config.tiny_corner_radius().into(),
                     width: 0.0,
                     color: Color::TRANSPARENT,
                 },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.1,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-                snap: true,
-            };
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
 
-            let base_style = modern_base(colors.purple, Color::WHITE);
+    /// Get an Modern-style theme for text inputs honoring this provider's config
+    pub fn text_input<'a>(&self) -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        let config = self.config;
+        move |theme, status| text_input_style(theme, status, &config)
+    }
 
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
+    /// Get a text input style for the leading part of an input group
+    ///
+    /// Rounds the left corners, or the right ones when `ModernConfig::rtl`
+    /// is set so the group reads from right to left.
+    pub fn input_group_input<'a>(
+        &self,
+    ) -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let base_style = text_input_style(theme, status, &config);
+            let radius = config.small_corner_radius();
+
+            text_input::Style {
+                border: Border {
+                    radius: if config.rtl {
+                        border::right(radius)
+                    } else {
+                        border::left(radius)
+                    },
+                    ..base_style.border
+                },
+                ..base_style
             }
         }
     }
 
-    /// Get a pink button style
-    pub fn pink_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+    /// Get a primary button style for the trailing part of an input group
+    ///
+    /// Rounds the right corners, or the left ones when `ModernConfig::rtl`
+    /// is set, mirroring `input_group_input`.
+    pub fn input_group_button<'a>(&self) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let config = self.config;
         move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
+            let base_style = button_style(theme, &style::Button::Primary, status, &config);
+            let radius = config.small_corner_radius();
 
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
+            button::Style {
                 border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.1,
-                        ..Color::BLACK
+                    radius: if config.rtl {
+                        border::left(radius)
+                    } else {
+                        border::right(radius)
                     },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
+                    ..base_style.border
                 },
-                snap: true,
-            };
+                ..base_style
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for radio buttons honoring this provider's config
+    pub fn radio<'a>(&self) -> impl Fn(&Theme, radio::Status) -> radio::Style + 'a {
+        let config = self.config;
+        move |theme, status| radio_style(theme, status, &config)
+    }
 
-            let base_style = modern_base(colors.pink, Color::WHITE);
+    /// Get a filled radio style honoring this provider's config
+    pub fn filled_radio<'a>(&self) -> impl Fn(&Theme, radio::Status) -> radio::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let colors = get_theme_colors_with(theme, &config);
+            let base_style = radio_style(theme, status, &config);
 
             match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
+                radio::Status::Active { is_selected: true }
+                | radio::Status::Hovered { is_selected: true } => radio::Style {
+                    background: Background::Color(colors.blue),
+                    dot_color: Color::WHITE,
+                    border_color: colors.blue,
+                    ..base_style
+                },
+                _ => base_style,
             }
         }
     }
 
-    /// Get an Modern-style gray button (neutral, subdued appearance)
-    pub fn gray_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+    /// Get a sized radio style honoring this provider's config
+    pub fn sized_radio<'a>(
+        &self,
+        size: ButtonSize,
+    ) -> impl Fn(&Theme, radio::Status) -> radio::Style + 'a {
+        let config = self.config;
         move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
+            let scale = Modern::control_size(size) / 16.0;
+            let base_style = radio_style(theme, status, &config);
 
-            // Gray color varies by theme
-            let gray_color = if is_dark {
-                colors::gray::GRAY3_DARK
-            } else {
-                colors::gray::GRAY4_LIGHT
-            };
+            radio::Style {
+                border_width: base_style.border_width * scale,
+                ..base_style
+            }
+        }
+    }
 
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
+    /// Get an Modern-style theme for checkboxes honoring this provider's config
+    pub fn checkbox<'a>(&self) -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a {
+        let config = self.config;
+        move |theme, status| checkbox_style(theme, status, &config)
+    }
+
+    /// Get a circular checkbox style honoring this provider's config
+    pub fn circle_checkbox<'a>(&self) -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let base_style = checkbox_style(theme, status, &config);
+
+            checkbox::Style {
                 border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+                    // Renderers clamp the radius to half the box, giving a circle
+                    radius: 1000.0.into(),
+                    ..base_style.border
                 },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.1,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
+                ..base_style
+            }
+        }
+    }
+
+    /// Get a sized checkbox style honoring this provider's config
+    pub fn sized_checkbox<'a>(
+        &self,
+        size: ButtonSize,
+    ) -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let scale = Modern::control_size(size) / 16.0;
+            let base_style = checkbox_style(theme, status, &config);
+
+            checkbox::Style {
+                border: Border {
+                    radius: (config.tiny_corner_radius() * scale).into(),
+                    width: base_style.border.width * scale,
+                    ..base_style.border
                 },
-                snap: true,
-            };
+                ..base_style
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for pick lists honoring this provider's config
+    pub fn pick_list<'a>(&self) -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        let config = self.config;
+        move |theme, status| pick_list_style(theme, status, &config)
+    }
 
-            let base_style = modern_base(gray_color, colors.text);
+    /// Get a disabled pick list style honoring this provider's config
+    pub fn disabled_pick_list<'a>(
+        &self,
+    ) -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        let config = self.config;
+        move |theme, _status| {
+            let base_style = pick_list_style(theme, pick_list::Status::Active, &config);
 
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
+            pick_list::Style {
+                text_color: base_style.text_color.scale_alpha(0.5),
+                placeholder_color: base_style.placeholder_color.scale_alpha(0.5),
+                handle_color: base_style.handle_color.scale_alpha(0.5),
+                background: map_background(base_style.background, |color| color.scale_alpha(0.7)),
+                border: Border {
+                    color: base_style.border.color.scale_alpha(0.5),
+                    ..base_style.border
+                },
             }
         }
     }
 
-    /// Get an Modern-style tinted button (semi-transparent colored background)
-    pub fn tinted_button<'a>(
-        color_variant: TintedButtonColor,
-    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+    /// Get a compact pick list style honoring this provider's config
+    pub fn compact_pick_list<'a>(
+        &self,
+    ) -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        let config = self.config;
         move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
+            let colors = get_theme_colors_with(theme, &config);
+            let base_style = pick_list_style(theme, status, &config);
 
-            // Get the base color based on the variant
-            let (base_color, _text_color) = match color_variant {
-                TintedButtonColor::Blue => (colors.blue, Color::WHITE),
-                TintedButtonColor::Green => (colors.green, Color::WHITE),
-                TintedButtonColor::Red => (colors.red, Color::WHITE),
-                TintedButtonColor::Orange => (colors.orange, Color::WHITE),
-                TintedButtonColor::Purple => (colors.purple, Color::WHITE),
-                TintedButtonColor::Teal => (colors.teal, Color::WHITE),
-                TintedButtonColor::Pink => (colors.pink, Color::WHITE),
-                TintedButtonColor::Indigo => (colors.indigo, Color::WHITE),
-            };
+            pick_list::Style {
+                border: Border {
+                    radius: config.tiny_corner_radius().into(),
+                    width: match status {
+                        pick_list::Status::Opened { .. } => 1.0,
+                        _ => 0.5,
+                    },
+                    ..base_style.border
+                },
+                handle_color: match status {
+                    pick_list::Status::Opened { .. } => base_style.handle_color,
+                    _ => colors.tertiary_text,
+                },
+                ..base_style
+            }
+        }
+    }
 
-            // Make color semi-transparent for tinted look
-            let tinted_color = Color {
-                r: base_color.r,
-                g: base_color.g,
-                b: base_color.b,
-                a: 0.2, // Low opacity for tinted appearance
-            };
+    /// Get a validated pick list style honoring this provider's config
+    pub fn validated_pick_list<'a>(
+        &self,
+        has_error: bool,
+    ) -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        let config = self.config;
+        move |theme, status| {
+            let base_style = pick_list_style(theme, status, &config);
 
-            // For tinted buttons, we usually want a stronger text color
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
+            if !has_error {
+                return base_style;
+            }
+
+            let colors = get_theme_colors_with(theme, &config);
+
+            pick_list::Style {
                 border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.05,
-                        ..Color::BLACK
-                    }, // Lighter shadow for tinted
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
+                    color: colors.red,
+                    ..base_style.border
                 },
-                snap: true,
-            };
+                handle_color: colors.red,
+                ..base_style
+            }
+        }
+    }
 
-            let base_style = modern_base(tinted_color, base_color); // Use the base color for text
+    /// Get an Modern-style theme for rules honoring this provider's config
+    ///
+    /// Applies `ModernConfig::separator_alpha` to the separator color.
+    pub fn rule<'a>(&self) -> impl Fn(&Theme) -> rule::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
 
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
+            rule::Style {
+                color: colors.separator,
+                radius: 0.0.into(),
+                fill_mode: rule::FillMode::Full,
+                snap: config.snap,
             }
         }
     }
 
-    /// Get an Modern-style blue tinted button
-    pub fn blue_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::tinted_button(TintedButtonColor::Blue)
+    /// Get a horizontal rule with a custom thickness and color honoring this provider's config
+    pub fn custom_rule<'a>(&self, thickness: u16, color: Color) -> rule::Rule<'a, Theme> {
+        let config = self.config;
+        rule::horizontal(u32::from(thickness)).style(move |_theme| solid_rule_style(color, &config))
+    }
+
+    /// Get a vertical rule with a custom thickness and color honoring this provider's config
+    pub fn custom_vertical_rule<'a>(&self, thickness: u16, color: Color) -> rule::Rule<'a, Theme> {
+        let config = self.config;
+        rule::vertical(u32::from(thickness)).style(move |_theme| solid_rule_style(color, &config))
+    }
+
+    /// Get a 2px horizontal accent rule honoring this provider's config
+    pub fn accent_rule<'a>(&self, color: TintedButtonColor) -> rule::Rule<'a, Theme> {
+        let config = self.config;
+        rule::horizontal(2).style(move |theme| {
+            solid_rule_style(
+                tinted_color(&get_theme_colors_with(theme, &config), color),
+                &config,
+            )
+        })
+    }
+
+    /// Get a 2px vertical accent rule honoring this provider's config
+    pub fn accent_vertical_rule<'a>(&self, color: TintedButtonColor) -> rule::Rule<'a, Theme> {
+        let config = self.config;
+        rule::vertical(2).style(move |theme| {
+            solid_rule_style(
+                tinted_color(&get_theme_colors_with(theme, &config), color),
+                &config,
+            )
+        })
+    }
+
+    /// Get an Modern-style theme for containers honoring this provider's config
+    pub fn container<'a>(
+        &self,
+        style: style::Container,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| container_style(theme, &style, &config)
+    }
+
+    /// Get an Modern-style theme for card containers
+    pub fn card_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        self.container(style::Container::Card)
+    }
+
+    /// Get a card container style lifted by `elevation` honoring this provider's config
+    pub fn dynamic_card<'a>(&self, elevation: f32) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        let card = self.card_container();
+        move |theme| {
+            let t = elevation.clamp(0.0, 1.0);
+            let lerp = |from: f32, to: f32| from + (to - from) * t;
+
+            container::Style {
+                shadow: scaled_shadow(
+                    Shadow {
+                        color: Color {
+                            a: lerp(CARD_SHADOW.color.a, FLOATING_SHADOW.color.a),
+                            ..Color::BLACK
+                        },
+                        offset: Vector::new(
+                            lerp(CARD_SHADOW.offset.x, FLOATING_SHADOW.offset.x),
+                            lerp(CARD_SHADOW.offset.y, FLOATING_SHADOW.offset.y),
+                        ),
+                        blur_radius: lerp(CARD_SHADOW.blur_radius, FLOATING_SHADOW.blur_radius),
+                    },
+                    &config,
+                ),
+                ..card(theme)
+            }
+        }
+    }
+
+    /// Get a flat card container style bounded by a hairline border honoring this provider's config
+    pub fn hairline_card<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        let card = self.card_container();
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+            let card = card(theme);
+
+            container::Style {
+                border: Border {
+                    width: 1.0,
+                    color: colors.separator.scale_alpha(0.5),
+                    ..card.border
+                },
+                shadow: Shadow::default(),
+                ..card
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for elevated containers
+    pub fn elevated_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        self.container(style::Container::Elevated)
+    }
+
+    /// Get an Modern-style theme for sheet containers
+    pub fn sheet_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        self.container(style::Container::Sheet)
+    }
+
+    /// Get a settings section body container style honoring this provider's config
+    pub fn form_section_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        self.container(style::Container::Group)
+    }
+
+    /// Get an Modern-style theme for sidebar containers honoring this provider's config
+    ///
+    /// The sidebar casts its shadow to the right, towards the content; with
+    /// `ModernConfig::rtl` the sidebar sits on the right and the shadow is
+    /// cast to the left instead.
+    pub fn sidebar_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        self.container(style::Container::Sidebar)
+    }
+
+    /// Get a sheet container style that squares its corners on narrow windows
+    ///
+    /// Pass the current window width. Below `ModernConfig::sheet_breakpoint`
+    /// the sheet usually spans the full width, so it drops its rounding
+    /// rather than showing rounded corners against the screen edge.
+    pub fn responsive_sheet_container<'a>(
+        &self,
+        width: f32,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let style = container_style(theme, &style::Container::Sheet, &config);
+
+            if width < config.sheet_breakpoint {
+                container::Style {
+                    border: Border {
+                        radius: 0.0.into(),
+                        ..style.border
+                    },
+                    ..style
+                }
+            } else {
+                style
+            }
+        }
+    }
+
+    /// Get a floating panel container style honoring this provider's config
+    pub fn floating_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| floating_container_style(theme, &config)
+    }
+
+    /// Get a container with separator line style honoring this provider's config
+    pub fn separated_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                text_color: Some(colors.text),
+                background: Some(Background::Color(colors.background)),
+                border: Border {
+                    radius: 0.0.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a bordered container style honoring this provider's config
+    pub fn bordered_container<'a>(
+        &self,
+        color: Color,
+        width: f32,
+        radius: f32,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                text_color: Some(colors.text),
+                background: None,
+                border: Border {
+                    radius: radius.into(),
+                    width,
+                    color,
+                },
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a branded container with accent color border honoring this provider's config
+    pub fn accent_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let provider = *self;
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                background: Some(Background::Color(colors.background)),
+                shadow: scaled_shadow(
+                    Shadow {
+                        color: Color {
+                            a: 0.1,
+                            ..Color::BLACK
+                        },
+                        offset: Vector::new(0.0, 2.0),
+                        blur_radius: 4.0,
+                    },
+                    &config,
+                ),
+                ..provider.bordered_container(colors.blue, 2.0, config.corner_radius)(theme)
+            }
+        }
+    }
+
+    /// Get a tinted blockquote or callout body style honoring this provider's config
+    pub fn accent_bar_container<'a>(
+        &self,
+        color: TintedButtonColor,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                text_color: Some(colors.text),
+                background: Some(Background::Color(
+                    tinted_color(&colors, color).scale_alpha(0.08),
+                )),
+                border: Border {
+                    radius: border::right(config.small_corner_radius()),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get the solid bar drawn left of an `accent_bar_container` honoring this provider's config
+    pub fn accent_bar<'a>(
+        &self,
+        color: TintedButtonColor,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                text_color: None,
+                background: Some(Background::Color(tinted_color(&colors, color))),
+                border: Border {
+                    radius: border::left(2.0),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a file drop zone container style honoring this provider's config
+    pub fn drop_zone_container<'a>(
+        &self,
+        active: bool,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+            let (border_color, background) = drop_zone_colors(&colors, active);
+
+            container::Style {
+                text_color: Some(colors.text),
+                background: Some(Background::Color(background)),
+                border: Border {
+                    radius: 10.0.into(),
+                    width: 2.0,
+                    color: border_color,
+                },
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get an image scrim container style honoring this provider's config
+    pub fn image_scrim_container<'a>(
+        &self,
+        direction: GradientDirection,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |_theme| {
+            let dark = Color::BLACK.scale_alpha(0.6);
+
+            // An angle of zero runs from the bottom edge to the top edge
+            let background = match direction {
+                GradientDirection::Bottom => Background::Gradient(
+                    Linear::new(Radians(0.0))
+                        .add_stop(0.0, dark)
+                        .add_stop(0.6, Color::TRANSPARENT)
+                        .into(),
+                ),
+                GradientDirection::Top => Background::Gradient(
+                    Linear::new(Radians(0.0))
+                        .add_stop(0.4, Color::TRANSPARENT)
+                        .add_stop(1.0, dark)
+                        .into(),
+                ),
+                GradientDirection::Full => Background::Color(Color::BLACK.scale_alpha(0.4)),
+            };
+
+            container::Style {
+                text_color: Some(Color::WHITE),
+                background: Some(background),
+                border: Border::default(),
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a selected list row container style honoring this provider's config
+    pub fn selected_row_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                text_color: Some(colors.text),
+                background: Some(Background::Color(colors.blue.scale_alpha(0.15))),
+                border: Border::default(),
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a keyboard shortcut key cap container style honoring this provider's config
+    pub fn keycap_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                text_color: Some(colors.secondary_text),
+                background: Some(Background::Color(colors.system_bg)),
+                border: Border {
+                    radius: config.tiny_corner_radius().into(),
+                    width: 1.0,
+                    color: colors.separator,
+                },
+                shadow: scaled_shadow(
+                    Shadow {
+                        color: Color {
+                            a: 0.15,
+                            ..Color::BLACK
+                        },
+                        offset: Vector::new(0.0, 1.0),
+                        blur_radius: 0.0,
+                    },
+                    &config,
+                ),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a toolbar container style honoring this provider's config
+    pub fn toolbar_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                text_color: Some(colors.text),
+                background: Some(Background::Color(colors.system_bg)),
+                border: Border {
+                    radius: 0.0.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: scaled_shadow(
+                    Shadow {
+                        color: Color {
+                            a: 0.05,
+                            ..Color::BLACK
+                        },
+                        offset: Vector::new(0.0, 1.0),
+                        blur_radius: 2.0,
+                    },
+                    &config,
+                ),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a container filled with an arbitrary color honoring this provider's config
+    pub fn filled_container<'a>(&self, color: Color) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            let text_color = if contrast_ratio(color, colors.text) < CONTRAST_AA {
+                on_color(color)
+            } else {
+                colors.text
+            };
+
+            container::Style {
+                text_color: Some(text_color),
+                background: Some(Background::Color(color)),
+                border: Border {
+                    radius: config.corner_radius.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a filled container with a directional shadow honoring this provider's config
+    pub fn shadowed_container<'a>(
+        &self,
+        background: Color,
+        direction: ShadowDirection,
+        elevation: f32,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let provider = *self;
+        let config = self.config;
+        move |theme| container::Style {
+            shadow: scaled_shadow(Modern::elevation_shadow(elevation, direction), &config),
+            ..provider.filled_container(background)(theme)
+        }
+    }
+
+    /// Get a focus ring container style honoring this provider's config
+    pub fn focus_ring_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            container::Style {
+                text_color: Some(colors.text),
+                background: None,
+                border: Border {
+                    radius: config.small_corner_radius().into(),
+                    width: 2.0,
+                    color: colors.blue,
+                },
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a split pane handle container style honoring this provider's config
+    pub fn split_handle_container<'a>(
+        &self,
+        hovered: bool,
+        dragging: bool,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            let color = if dragging {
+                colors.blue
+            } else if hovered {
+                blend(colors.separator, colors.blue, 0.5)
+            } else {
+                colors.separator
+            };
+
+            container::Style {
+                text_color: None,
+                background: Some(Background::Color(color)),
+                border: Border::default(),
+                shadow: Shadow::default(),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a danger tooltip container style honoring this provider's config
+    pub fn danger_tooltip_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            // Determine if dark mode
+            let dark_mode = is_dark_mode(theme);
+
+            let background = if dark_mode {
+                Color::from_rgb(0.4, 0.1, 0.1) // More saturated dark red
+            } else {
+                Color::from_rgb(1.0, 0.92, 0.92) // softer, clearer red
+            };
+
+            let text_color = if dark_mode {
+                Color::from_rgb(1.0, 0.6, 0.6) // lighter red for better contrast
+            } else {
+                Color::from_rgb(0.7, 0.0, 0.0) // slightly darker red for better readability
+            };
+
+            container::Style {
+                // Guarantee AA contrast should the colors above ever change
+                text_color: Some(ensure_contrast(text_color, background, CONTRAST_AA)),
+                background: Some(Background::Color(background)),
+                border: Border {
+                    radius: config.small_corner_radius().into(),
+                    width: 1.0,
+                    color: if dark_mode {
+                        Color::from_rgb(0.8, 0.3, 0.3)
+                    } else {
+                        Color::from_rgb(0.9, 0.6, 0.6)
+                    },
+                },
+                shadow: scaled_shadow(
+                    Shadow {
+                        color: Color {
+                            r: 0.0,
+                            g: 0.0,
+                            b: 0.0,
+                            a: 0.15,
+                        },
+                        offset: Vector::new(0.0, 1.0),
+                        blur_radius: 3.0,
+                    },
+                    &config,
+                ),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a warning tooltip container style honoring this provider's config
+    pub fn warning_tooltip_container<'a>(&self) -> impl Fn(&Theme) -> container::Style + 'a {
+        let config = self.config;
+        move |theme| {
+            let colors = get_theme_colors_with(theme, &config);
+
+            let background = if is_dark_mode(theme) {
+                // Darker theme - subtle orange-brown background, blended over
+                // the window background so its contrast is known
+                let tint = Color::from_rgb(0.3, 0.15, 0.0);
+                blend(colors.background, tint, 0.7)
+            } else {
+                // Light theme - very subtle light orange background
+                Color::from_rgb(1.0, 0.96, 0.9)
+            };
+
+            container::Style {
+                // Orange text for warnings, deepened where needed to reach AA
+                text_color: Some(ensure_contrast(colors.orange, background, CONTRAST_AA)),
+                background: Some(Background::Color(background)),
+                border: Border {
+                    radius: config.small_corner_radius().into(), // Slightly rounded corners
+                    width: 1.0,
+                    color: colors.orange, // Orange border to match the warning theme
+                },
+                shadow: scaled_shadow(
+                    Shadow {
+                        color: Color {
+                            a: 0.1,
+                            ..Color::BLACK
+                        },
+                        offset: Vector::new(0.0, 1.0),
+                        blur_radius: 2.0,
+                    },
+                    &config,
+                ),
+                snap: config.snap,
+            }
+        }
+    }
+
+    /// Get a fingerprint of a theme as styled by this provider
+    ///
+    /// Hashes the theme name, every resolved color role and the config, so
+    /// it changes whenever the user switches theme or accent. Use it to key
+    /// caches of derived resources such as gradients. Stable within a run of
+    /// the program only; don't persist it.
+    pub fn theme_fingerprint(&self, theme: &Theme) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let hash_color = |color: Color, hasher: &mut DefaultHasher| {
+            for channel in [color.r, color.g, color.b, color.a] {
+                channel.to_bits().hash(hasher);
+            }
+        };
+
+        let mut hasher = DefaultHasher::new();
+        theme.to_string().hash(&mut hasher);
+
+        for (role, color) in get_theme_colors_with(theme, &self.config).entries() {
+            role.hash(&mut hasher);
+            hash_color(color, &mut hasher);
+        }
+
+        // Destructured so new options can't be forgotten here
+        let ModernConfig {
+            accent,
+            corner_radius,
+            outline_fill_on_hover,
+            success_on_valid,
+            shadow_on_press,
+            button_sheen,
+            press_flash,
+            text_button_hover_bg,
+            surface_tint,
+            rtl,
+            snap,
+            reduce_transparency,
+            sheet_breakpoint,
+            tint_alpha,
+            separator_alpha,
+            shadow_alpha,
+            gray_ramp,
+            warmth,
+            desaturate_dark_accents,
+        } = self.config;
+
+        accent.is_some().hash(&mut hasher);
+        if let Some(accent) = accent {
+            hash_color(accent, &mut hasher);
+        }
+        gray_ramp.is_some().hash(&mut hasher);
+        if let Some(ramp) = gray_ramp {
+            for gray in ramp
+                .light
+                .into_iter()
+                .chain(ramp.dark)
+                .chain([ramp.separator_light, ramp.separator_dark])
+            {
+                hash_color(gray, &mut hasher);
+            }
+        }
+        corner_radius.to_bits().hash(&mut hasher);
+        sheet_breakpoint.to_bits().hash(&mut hasher);
+        tint_alpha.to_bits().hash(&mut hasher);
+        separator_alpha.to_bits().hash(&mut hasher);
+        shadow_alpha.to_bits().hash(&mut hasher);
+        warmth.to_bits().hash(&mut hasher);
+        [
+            outline_fill_on_hover,
+            success_on_valid,
+            shadow_on_press,
+            button_sheen,
+            press_flash,
+            text_button_hover_bg,
+            surface_tint,
+            rtl,
+            snap,
+            reduce_transparency,
+            desaturate_dark_accents,
+        ]
+        .hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// Check a theme's text and background pairs against WCAG AA, with this provider's accent
+    ///
+    /// Returns the failing pairs; see `audit_contrast` for the rules. Handy
+    /// for validating a brand accent before shipping it.
+    pub fn audit_contrast(&self, theme: &Theme) -> Vec<ContrastIssue> {
+        audit_contrast(&get_theme_colors_with(theme, &self.config))
+    }
+
+    /// Export a theme as design-token JSON, with this provider's radii and accent
+    #[cfg(feature = "serde")]
+    pub fn export_tokens(&self, theme: &Theme) -> String {
+        let tokens = crate::tokens::DesignTokens::from_theme(theme, &self.config);
+
+        serde_json::to_string_pretty(&tokens).expect("design tokens serialize to JSON")
+    }
+
+    /// Dynamically choose between danger, warning and valid text input styles
+    ///
+    /// Valid input uses the inline style, or the green success style when
+    /// `ModernConfig::success_on_valid` is set.
+    pub fn conditional_text_input<'a>(
+        &self,
+        validation_state: ValidationState,
+    ) -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        let success_on_valid = self.config.success_on_valid;
+        move |theme, status| {
+            match validation_state {
+                ValidationState::Error => {
+                    // Call the danger function and immediately apply it
+                    (Modern::danger_text_input())(theme, status)
+                }
+                ValidationState::Warning => {
+                    // Call the warning function and immediately apply it
+                    (Modern::warning_text_input())(theme, status)
+                }
+                ValidationState::Valid if success_on_valid => {
+                    // Affirm valid input with a green border
+                    (Modern::success_text_input())(theme, status)
+                }
+                ValidationState::Valid => {
+                    // Call the inline function and immediately apply it
+                    (Modern::inline_text_input())(theme, status)
+                }
+            }
+        }
+    }
+}
+
+/// Every semantic button style in one value
+///
+/// Obtained through `Modern::button_styles`. Each field holds the same
+/// style as the matching `Modern` method (e.g. `primary` is
+/// `Modern::primary_button`), as a plain function pointer so the struct is
+/// `Copy` and can be destructured once and reused across a whole toolbar.
+#[derive(Debug, Clone, Copy)]
+pub struct ButtonStyles {
+    pub primary: fn(&Theme, ButtonStatus) -> button::Style,
+    pub secondary: fn(&Theme, ButtonStatus) -> button::Style,
+    pub success: fn(&Theme, ButtonStatus) -> button::Style,
+    pub warning: fn(&Theme, ButtonStatus) -> button::Style,
+    pub danger: fn(&Theme, ButtonStatus) -> button::Style,
+    pub link: fn(&Theme, ButtonStatus) -> button::Style,
+    pub system: fn(&Theme, ButtonStatus) -> button::Style,
+    pub plain: fn(&Theme, ButtonStatus) -> button::Style,
+    pub ghost: fn(&Theme, ButtonStatus) -> button::Style,
+}
+
+impl Modern {
+    /// Get a provider whose styles honor the given config
+    pub fn with_config(config: ModernConfig) -> ModernProvider {
+        ModernProvider::new(config)
+    }
+
+    /// Get a provider whose styles use the given brand accent instead of blue
+    ///
+    /// Pair with `Modern::themed` so the theme palette uses the same accent.
+    pub fn with_accent(accent: Color) -> ModernProvider {
+        ModernProvider::new(ModernConfig {
+            accent: Some(accent),
+            ..ModernConfig::default()
+        })
+    }
+
+    /// Get a provider whose styles are shifted warmer or cooler
+    ///
+    /// `kelvin_shift` is negative for warmer, positive for cooler; -500 to
+    /// -1500 suits night use and the shift is clamped to ±2000. Applies to
+    /// every color role of the widget styles, not the theme palette.
+    pub fn with_warmth(kelvin_shift: f32) -> ModernProvider {
+        ModernProvider::new(ModernConfig {
+            warmth: kelvin_shift,
+            ..ModernConfig::default()
+        })
+    }
+
+    /// Get a provider whose styles use a built-in accent preset
+    ///
+    /// Presets differ between light and dark mode, so pass the mode and
+    /// create the matching theme with
+    /// `Modern::themed(dark_mode, preset.accent(dark_mode))`.
+    pub fn with_accent_preset(preset: AccentPreset, dark_mode: bool) -> ModernProvider {
+        Self::with_accent(preset.accent(dark_mode))
+    }
+
+    /// Get an Modern-style theme for buttons
+    pub fn button<'a>(style: style::Button) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().button(style)
+    }
+
+    /// Get every semantic button style at once
+    ///
+    /// Handy when a view uses many kinds of buttons:
+    /// `let ButtonStyles { primary, danger, plain, .. } = Modern::button_styles();`
+    pub fn button_styles() -> ButtonStyles {
+        ButtonStyles {
+            primary: |theme, status| Self::primary_button()(theme, status),
+            secondary: |theme, status| Self::secondary_button()(theme, status),
+            success: |theme, status| Self::success_button()(theme, status),
+            warning: |theme, status| Self::warning_button()(theme, status),
+            danger: |theme, status| Self::danger_button()(theme, status),
+            link: |theme, status| Self::link_button()(theme, status),
+            system: |theme, status| Self::system_button()(theme, status),
+            plain: |theme, status| Self::plain_button()(theme, status),
+            ghost: |theme, status| Self::ghost_button()(theme, status),
+        }
+    }
+
+    /// Get an Modern-style theme for primary buttons (blue)
+    pub fn primary_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::Primary)
+    }
+
+    /// Get an Modern-style theme for secondary buttons (outlined)
+    ///
+    /// Shows a subtle accent tint on hover. Use a provider with
+    /// `ModernConfig::outline_fill_on_hover` to fill with the accent instead.
+    pub fn secondary_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::Secondary)
+    }
+
+    /// Get an Modern-style theme for success buttons (green)
+    pub fn success_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::Success)
+    }
+
+    /// Get an Modern-style theme for warning buttons (orange)
+    pub fn warning_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::Warning)
+    }
+
+    /// Get an Modern-style theme for danger buttons (red)
+    pub fn danger_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::Danger)
+    }
+
+    /// Get a danger button that escalates its red while hovered
+    ///
+    /// At rest it matches `danger_button`. On hover the red becomes fully
+    /// saturated and deeper than the usual hover nudge, and more so when
+    /// pressed, so the destructive action reads as such exactly when the
+    /// cursor is over it. It deepens in dark mode too, since a lighter red
+    /// would wash out the white label; the label never drops below
+    /// its resting contrast or `CONTRAST_AA_LARGE`. Disabled stays dimmed.
+    pub fn escalating_danger_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        move |theme, status| {
+            let colors = get_theme_colors(theme);
+            let base_style = Self::danger_button()(theme, status);
+
+            // The label never loses contrast against its resting red
+            let rest_label = accent_label_color(colors.red);
+            let rest_contrast = contrast_ratio(rest_label, colors.red);
+
+            // Saturate fully and deepen, keeping the label legible on the result
+            let escalate = |step: f32| {
+                let (hue, _, lightness) = to_hsl(colors.red);
+                let background = from_hsl(hue, 1.0, (lightness - step).max(0.25));
+
+                button::Style {
+                    background: Some(Background::Color(background)),
+                    text_color: ensure_contrast(
+                        rest_label,
+                        background,
+                        rest_contrast.max(CONTRAST_AA_LARGE),
+                    ),
+                    ..base_style
+                }
+            };
+
+            match status {
+                ButtonStatus::Hovered => escalate(0.08),
+                ButtonStatus::Pressed => escalate(0.14),
+                _ => base_style,
+            }
+        }
+    }
+
+    /// Get a danger button style visualizing press-and-hold progress
+    ///
+    /// `fill` ranges from 0.0 to 1.0; that proportion of the button, from the
+    /// left, is drawn in a deeper red. Drive it from your own hold timer to
+    /// build "hold to delete" buttons. Hover and press shade both parts like
+    /// a regular danger button.
+    pub fn danger_button_progress<'a>(
+        fill: f32,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().danger_button_progress(fill)
+    }
+
+    /// Get an Modern-style theme for link buttons (text-only)
+    ///
+    /// Hovering fades the text slightly and pressing deepens it to a pressed
+    /// link color. Button styles can't underline their label; for an
+    /// underlined link use `Modern::link_text_underlined` in a `rich_text`.
+    pub fn link_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::Link)
+    }
+
+    /// Get an Modern-style theme for system buttons (light gray)
+    pub fn system_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::System)
+    }
+
+    /// Get an Modern-style theme for plain buttons (text only without link color)
+    pub fn plain_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::Plain)
+    }
+
+    /// Get an Modern-style theme for text inputs
+    pub fn text_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        ModernProvider::default().text_input()
+    }
+
+    /// Get an Modern-style theme for containers
+    pub fn container<'a>(style: style::Container) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().container(style)
+    }
+
+    /// Get an Modern-style theme for card containers
+    pub fn card_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        Self::container(style::Container::Card)
+    }
+
+    /// Get a card container style with pixel snapping turned on or off
+    ///
+    /// Pass `false` for cards that are animated or moved by fractional
+    /// amounts, where snapping to the pixel grid makes them jitter. Use
+    /// `ModernConfig::snap` to change the default for every container.
+    pub fn card_container_snap<'a>(snap: bool) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::new(ModernConfig {
+            snap,
+            ..ModernConfig::default()
+        })
+        .card_container()
+    }
+
+    /// Get an Modern-style theme for elevated containers
+    ///
+    /// Sits between the other surfaces: use `card_container` for content
+    /// resting on the page, `elevated_container` for popovers and menus that
+    /// hover just above it, and `floating_container` for detached panels
+    /// that should stand out the most.
+    pub fn elevated_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        Self::container(style::Container::Elevated)
+    }
+
+    /// Get a card container style lifted by `elevation`, from 0.0 to 1.0
+    ///
+    /// At 0.0 it matches `card_container`; towards 1.0 the shadow grows in
+    /// offset, blur and opacity up to the `floating_container` shadow. Drive
+    /// `elevation` from a hover animation in your own state for cards that
+    /// lift when hovered. Moving cards jitter when snapped to the pixel grid,
+    /// so call `ModernProvider::dynamic_card` on a provider whose config sets
+    /// `snap: false` for them.
+    pub fn dynamic_card<'a>(elevation: f32) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().dynamic_card(elevation)
+    }
+
+    /// Get a flat card container style bounded by a hairline border
+    ///
+    /// Drops the card shadow for a 1px `separator` border at half opacity,
+    /// drawn inside the bounds so it doesn't change the layout. Keeps cards
+    /// delineated on surfaces of the same color in data-dense views.
+    pub fn hairline_card<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().hairline_card()
+    }
+
+    /// Get an Modern-style theme for sheet containers
+    pub fn sheet_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        Self::container(style::Container::Sheet)
+    }
+
+    /// Get a sheet container style with square corners below 600px of width
+    ///
+    /// Pass the current window width. Override the breakpoint with
+    /// `ModernConfig::sheet_breakpoint` through `Modern::with_config`.
+    pub fn responsive_sheet_container<'a>(width: f32) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().responsive_sheet_container(width)
+    }
+
+    /// Get an Modern-style theme for group containers
+    pub fn group_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        Self::container(style::Container::Group)
+    }
+
+    /// Get a container style for the body of a grouped settings section
+    ///
+    /// Lay a section out as a column: a `form_section_header_text` label,
+    /// then this container holding the section's rows separated by
+    /// `inset_rule`s. Leave about 24px between sections and 6px between a
+    /// header and its body.
+    pub fn form_section_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().form_section_container()
+    }
+
+    /// Get an Modern-style theme for sidebar containers
+    pub fn sidebar_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().sidebar_container()
+    }
+
+    /// Get a scrollable style that blends into `sidebar_container`
+    ///
+    /// The rail is transparent over the sidebar background and the scroller
+    /// is a shade of it, darker in light mode and lighter in dark mode,
+    /// deepening on hover and while dragged.
+    pub fn sidebar_scrollable<'a>() -> impl Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a
+    {
+        move |theme, status| {
+            let colors = get_theme_colors(theme);
+            let sidebar_bg = sidebar_background(theme, &colors);
+
+            let rail = |strength: f32| scrollable::Rail {
+                background: None,
+                border: border::rounded(4),
+                scroller: scrollable::Scroller {
+                    background: Background::Color(blend(sidebar_bg, colors.text, strength)),
+                    border: border::rounded(4),
+                },
+            };
+
+            // Scroller strength at rest, and when its bar is engaged
+            let level = |engaged: bool, strength: f32| if engaged { strength } else { 0.2 };
+
+            let (vertical, horizontal) = match status {
+                scrollable::Status::Active { .. } => (0.2, 0.2),
+                scrollable::Status::Hovered {
+                    is_horizontal_scrollbar_hovered,
+                    is_vertical_scrollbar_hovered,
+                    ..
+                } => (
+                    level(is_vertical_scrollbar_hovered, 0.35),
+                    level(is_horizontal_scrollbar_hovered, 0.35),
+                ),
+                scrollable::Status::Dragged {
+                    is_horizontal_scrollbar_dragged,
+                    is_vertical_scrollbar_dragged,
+                    ..
+                } => (
+                    level(is_vertical_scrollbar_dragged, 0.45),
+                    level(is_horizontal_scrollbar_dragged, 0.45),
+                ),
+            };
+
+            scrollable::Style {
+                vertical_rail: rail(vertical),
+                horizontal_rail: rail(horizontal),
+                gap: None,
+                ..scrollable::default(theme, status)
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for radio buttons
+    pub fn radio<'a>() -> impl Fn(&Theme, radio::Status) -> radio::Style + 'a {
+        ModernProvider::default().radio()
+    }
+
+    /// Get a radio style that fills the whole circle when selected
+    ///
+    /// The selected circle is filled with the accent and shows a white dot,
+    /// like a checked checkbox. Unselected radios look like `Modern::radio`.
+    /// Iced radios have no disabled status, so there is none to style.
+    pub fn filled_radio<'a>() -> impl Fn(&Theme, radio::Status) -> radio::Style + 'a {
+        ModernProvider::default().filled_radio()
+    }
+
+    /// Get a radio style with a custom label color
+    ///
+    /// Handy for dimming less prominent options, e.g. with
+    /// `ThemeColors::secondary_text`. Take the color from the current theme
+    /// so it follows light and dark mode; the circle is styled as usual.
+    pub fn radio_colored_label<'a>(
+        label_color: Color,
+    ) -> impl Fn(&Theme, radio::Status) -> radio::Style + 'a {
+        move |theme, status| radio::Style {
+            text_color: Some(label_color),
+            ..radio_style(theme, status, &ModernConfig::default())
+        }
+    }
+
+    /// Get an Modern-style theme for checkboxes
+    pub fn checkbox<'a>() -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a {
+        ModernProvider::default().checkbox()
+    }
+
+    /// Get a checkbox style filled with the given color when checked
+    ///
+    /// The check mark stays white on saturated fills and turns dark on light
+    /// ones (e.g. yellow) so it is always legible. Use a theme color such as
+    /// `ThemeColors::green` to keep it consistent across light and dark mode.
+    pub fn colored_checkbox<'a>(
+        color: Color,
+    ) -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a {
+        move |theme, status| colored_checkbox_style(theme, status, color, &ModernConfig::default())
+    }
+
+    /// Get a circular checkbox style, for a rounder toggle-dot look
+    ///
+    /// Fills with the accent and a white check when checked, like
+    /// `Modern::checkbox`, with the same hover and disabled states. Works at
+    /// any checkbox size.
+    pub fn circle_checkbox<'a>() -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a {
+        ModernProvider::default().circle_checkbox()
+    }
+
+    /// Get a checkbox style for the indeterminate (mixed) state
+    ///
+    /// Iced checkboxes have no mixed state, so drive it yourself: while a
+    /// parent's group is partially selected, render the checkbox as checked
+    /// with this style and `Modern::indeterminate_checkbox_icon` as its icon.
+    /// It looks filled like a checked box whatever `is_checked` reports.
+    pub fn indeterminate_checkbox<'a>() -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a
+    {
+        move |theme, status| {
+            let status = match status {
+                checkbox::Status::Active { .. } => checkbox::Status::Active { is_checked: true },
+                checkbox::Status::Hovered { .. } => checkbox::Status::Hovered { is_checked: true },
+                checkbox::Status::Disabled { .. } => {
+                    checkbox::Status::Disabled { is_checked: true }
+                }
+            };
+
+            checkbox_style(theme, status, &ModernConfig::default())
+        }
+    }
+
+    /// Get the dash icon drawn in an indeterminate checkbox
+    pub fn indeterminate_checkbox_icon() -> checkbox::Icon<Font> {
+        checkbox::Icon {
+            font: Font::DEFAULT,
+            code_point: '\u{2013}', // En dash
+            size: None,
+            line_height: text::LineHeight::default(),
+            shaping: text::Shaping::Basic,
+        }
+    }
+
+    /// Get an Modern-style theme for pick lists
+    pub fn pick_list<'a>() -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        ModernProvider::default().pick_list()
+    }
+
+    /// Get a pick list style for a dropdown that is currently disabled
+    ///
+    /// Iced pick lists have no disabled status, so swap this style in (and
+    /// ignore selections) while the control is unavailable. It dims the
+    /// value, placeholder, handle, background and border like a disabled
+    /// text input.
+    pub fn disabled_pick_list<'a>() -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        ModernProvider::default().disabled_pick_list()
+    }
+
+    /// Get a pick list style that flags a validation error
+    ///
+    /// With `has_error` the border and handle turn red in every status, so
+    /// the error stays visible while the menu is open; otherwise this is the
+    /// regular `Modern::pick_list` style. The pick list counterpart of
+    /// `Modern::validated_text_input`.
+    pub fn validated_pick_list<'a>(
+        has_error: bool,
+    ) -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        ModernProvider::default().validated_pick_list(has_error)
+    }
+
+    /// Get a compact pick list style for dense toolbars and filter rows
+    ///
+    /// Uses tighter corners, a hairline border and a lighter handle so the
+    /// dropdown recedes next to table content. Pair with
+    /// `Modern::compact_pick_list_padding` and a small `text_size`.
+    pub fn compact_pick_list<'a>() -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        ModernProvider::default().compact_pick_list()
+    }
+
+    /// Get the recommended padding for `Modern::compact_pick_list`
+    pub fn compact_pick_list_padding() -> Padding {
+        Padding::from([3, 6])
+    }
+
+    /// Get an Modern-style theme for rules (plain separators)
+    pub fn rule<'a>() -> impl Fn(&Theme) -> rule::Style + 'a {
+        ModernProvider::default().rule()
+    }
+
+    /// Get an Modern-style theme for inset rules (list separators indented on the leading side)
+    pub fn inset_rule<'a>() -> impl Fn(&Theme) -> rule::Style + 'a {
+        |theme| rule::Style {
+            fill_mode: rule::FillMode::AsymmetricPadding(16, 0),
+            ..(Self::rule())(theme)
+        }
+    }
+
+    /// Get a horizontal rule with a custom thickness and color (e.g. an accent tab underline)
+    ///
+    /// Iced sets the thickness of a rule on the widget rather than its style,
+    /// so this returns the styled rule itself. It fills the full width; use
+    /// `custom_vertical_rule` for a vertical divider.
+    pub fn custom_rule<'a>(thickness: u16, color: Color) -> rule::Rule<'a, Theme> {
+        ModernProvider::default().custom_rule(thickness, color)
+    }
+
+    /// Get a vertical rule with a custom thickness and color
+    ///
+    /// The vertical counterpart of `custom_rule`, e.g. for an emphasized
+    /// divider between toolbar groups. It fills the full height.
+    pub fn custom_vertical_rule<'a>(thickness: u16, color: Color) -> rule::Rule<'a, Theme> {
+        ModernProvider::default().custom_vertical_rule(thickness, color)
+    }
+
+    /// Get a 2px horizontal rule in an accent color
+    ///
+    /// Meant as an active-tab underline under a `tab_button` or a selected
+    /// row marker; keep `rule` for plain separators. Like `custom_rule`, it
+    /// returns the styled rule itself because Iced sets thickness on the
+    /// widget.
+    pub fn accent_rule<'a>(color: TintedButtonColor) -> rule::Rule<'a, Theme> {
+        ModernProvider::default().accent_rule(color)
+    }
+
+    /// Get a 2px vertical rule in an accent color
+    ///
+    /// The vertical counterpart of `accent_rule`, e.g. a selection marker
+    /// on the leading edge of a sidebar item.
+    pub fn accent_vertical_rule<'a>(color: TintedButtonColor) -> rule::Rule<'a, Theme> {
+        ModernProvider::default().accent_vertical_rule(color)
+    }
+
+    /// Get an Modern-style theme for progress bars of unknown duration
+    ///
+    /// Draws an accent bar over the input background track. Iced's progress
+    /// bar is value-driven and always fills from the start of its range, so
+    /// a detached moving stripe isn't possible; instead use a `0.0..=1.0`
+    /// range and feed `Modern::indeterminate_progress_value` the elapsed time
+    /// on every frame (e.g. from `iced::window::frames`) for a repeating sweep.
+    pub fn indeterminate_progress_bar<'a>() -> impl Fn(&Theme) -> progress_bar::Style + 'a {
+        |theme| {
+            let colors = get_theme_colors(theme);
+
+            progress_bar::Style {
+                background: Background::Color(colors.input_bg),
+                bar: Background::Color(colors.blue),
+                border: Border {
+                    radius: SMALL_CORNER_RADIUS.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+            }
+        }
+    }
+
+    /// Get the animated value of an indeterminate progress bar, from 0.0 to 1.0
+    ///
+    /// `seconds` is the time elapsed since the operation started. The bar
+    /// sweeps from empty to full with an ease-in-out curve every 1.5 seconds.
+    pub fn indeterminate_progress_value(seconds: f32) -> f32 {
+        const PERIOD: f32 = 1.5;
+
+        let t = seconds.max(0.0) % PERIOD / PERIOD;
+        t * t * (3.0 - 2.0 * t)
+    }
+
+    /// Get the fill ratio and style of a progress bar showing "step `current` of `total`"
+    ///
+    /// Completed steps are drawn in the accent over an input-background track.
+    /// Use the ratio with a `0.0..=1.0` range:
+    /// `let (value, style) = Modern::stepped_progress(5, 2);` then
+    /// `progress_bar(0.0..=1.0, value).style(style)`. Iced's progress bar is
+    /// continuous; for visibly separate segments, lay out `total` short bars
+    /// in a row with a small spacing and fill the first `current` of them.
+    pub fn stepped_progress<'a>(
+        total: u32,
+        current: u32,
+    ) -> (f32, impl Fn(&Theme) -> progress_bar::Style + 'a) {
+        let ratio = if total == 0 {
+            0.0
+        } else {
+            current.min(total) as f32 / total as f32
+        };
+
+        let style = |theme: &Theme| {
+            let colors = get_theme_colors(theme);
+
+            progress_bar::Style {
+                background: Background::Color(colors.input_bg),
+                bar: Background::Color(colors.blue),
+                border: Border {
+                    radius: SMALL_CORNER_RADIUS.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+            }
+        };
+
+        (ratio, style)
+    }
+
+    /// Create a complete Modern-styled theme
+    pub fn theme(dark_mode: bool) -> Theme {
+        create_modern_theme(dark_mode, None)
+    }
+
+    /// Create a complete Modern-styled theme together with a provider for the given config
+    ///
+    /// The theme uses the config's accent (if any) as its primary color, and
+    /// the provider's styles honor the accent, corner radius and other options,
+    /// so Modern can be configured in one place at startup.
+    pub fn theme_with_config(dark_mode: bool, config: ModernConfig) -> (Theme, ModernProvider) {
+        (
+            create_modern_theme(dark_mode, config.accent),
+            ModernProvider::new(config),
+        )
+    }
+
+    /// Create a complete Modern-styled theme with a custom primary accent
+    ///
+    /// Success, danger and warning colors stay standard. Use
+    /// `Modern::with_accent` with the same color so widget styles match.
+    pub fn themed(dark_mode: bool, accent: Color) -> Theme {
+        create_modern_theme(dark_mode, Some(accent))
+    }
+
+    /// Export a theme as design-token JSON
+    ///
+    /// Produces every color role as a hex string plus the corner radii and
+    /// container shadows, so other platforms can share the exact values.
+    #[cfg(feature = "serde")]
+    pub fn export_tokens(theme: &Theme) -> String {
+        ModernProvider::default().export_tokens(theme)
+    }
+
+    /// Rebuild a theme and config from design-token JSON
+    ///
+    /// Accepts the output of `export_tokens`; use the returned config with
+    /// `Modern::with_config` so widget styles match the imported theme.
+    #[cfg(feature = "serde")]
+    pub fn import_tokens(json: &str) -> Result<(Theme, ModernConfig), serde_json::Error> {
+        serde_json::from_str::<crate::tokens::DesignTokens>(json)?.to_theme()
+    }
+
+    /// Create a high-contrast Modern-styled theme
+    ///
+    /// Uses pure black/white backgrounds and the accessible accent colors,
+    /// and strengthens secondary text, placeholders and borders in every
+    /// widget style.
+    pub fn high_contrast_theme(dark_mode: bool) -> Theme {
+        create_high_contrast_theme(dark_mode)
+    }
+
+    /// Create a warm sepia Modern-styled theme for reading apps
+    ///
+    /// Cream background, dark-brown text and muted, low-blue accents. It
+    /// is a light theme, and every widget style picks up its paper-toned
+    /// cards, inputs and separators.
+    pub fn sepia_theme() -> Theme {
+        Theme::custom(
+            String::from("Modern Sepia"),
+            iced::theme::Palette {
+                background: colors::sepia::BACKGROUND,
+                text: colors::sepia::TEXT,
+                primary: colors::sepia::BLUE,
+                success: colors::sepia::GREEN,
+                danger: colors::sepia::RED,
+                warning: colors::sepia::ORANGE,
+            },
+        )
+    }
+
+    /// Create a pure-black Modern-styled dark theme for OLED displays
+    ///
+    /// Uses a `#000000` background instead of the dark theme's `#1C1C1E`,
+    /// with slightly lifted grays for cards, groups, sheets, sidebars and
+    /// inputs so they stay distinguishable. Accents match the dark theme.
+    pub fn oled_dark_theme() -> Theme {
+        Theme::custom(
+            String::from("Modern OLED Dark"),
+            iced::theme::Palette {
+                background: colors::oled::BACKGROUND,
+                text: Color::WHITE,
+                primary: MODERN_BLUE_DARK,
+                success: MODERN_GREEN_DARK,
+                danger: MODERN_RED_DARK,
+                warning: MODERN_ORANGE_DARK,
+            },
+        )
+    }
+
+    /// Create a theme chosen by the `MODERN_THEME` environment variable
+    ///
+    /// Accepts `light`, `dark`, `oled`, `high-contrast`, `high-contrast-dark`
+    /// and `sepia` (case-insensitive) and falls back to the light theme, so
+    /// screenshot tooling and CI can switch themes without recompiling.
+    pub fn theme_from_env() -> Theme {
+        let variant = std::env::var("MODERN_THEME").unwrap_or_default();
+
+        let variant = match variant.trim().to_ascii_lowercase().as_str() {
+            "dark" => ModernVariant::Dark,
+            "oled" => ModernVariant::OledDark,
+            "high-contrast" => ModernVariant::HighContrastLight,
+            "high-contrast-dark" => ModernVariant::HighContrastDark,
+            "sepia" => ModernVariant::Sepia,
+            _ => ModernVariant::Light,
+        };
+
+        variant.theme()
+    }
+
+    /// Derive a dark theme from a light one
+    ///
+    /// Inverts the palette with `invert_palette`, so accents keep their hue.
+    /// The theme is named after the source with "Light" replaced by "Dark"
+    /// (or " Dark" appended), so the Modern widget styles pick dark colors.
+    /// Themes that are already dark are returned unchanged.
+    pub fn auto_dark(light_theme: &Theme) -> Theme {
+        if is_dark_mode(light_theme) {
+            return light_theme.clone();
+        }
+
+        let name = light_theme.to_string();
+        let name = if name.contains("Light") {
+            name.replace("Light", "Dark")
+        } else {
+            format!("{name} Dark")
+        };
+
+        Theme::custom(name, invert_palette(&light_theme.palette()))
+    }
+
+    /// Blend two color roles of a theme, from `role_a` at `t = 0.0` to `role_b` at `t = 1.0`
+    ///
+    /// Roles are picked with selectors on `ThemeColors`, e.g.
+    /// `Modern::blend_roles(theme, |c| c.separator, |c| c.blue, 0.5)` for a
+    /// hover color halfway between them. Blends in linear light like `blend`.
+    pub fn blend_roles(
+        theme: &Theme,
+        role_a: impl Fn(&ThemeColors) -> Color,
+        role_b: impl Fn(&ThemeColors) -> Color,
+        t: f32,
+    ) -> Color {
+        let colors = get_theme_colors(theme);
+
+        blend(role_a(&colors), role_b(&colors), t)
+    }
+
+    /// Describe how the Modern styles resolve a theme, for bug reports
+    ///
+    /// Lists the detected mode, the resolved accent, the theme palette and
+    /// every color role as hex. Builds a fresh string on each call, so keep
+    /// it out of `view` and style functions.
+    pub fn describe_theme(theme: &Theme) -> String {
+        use std::fmt::Write;
+
+        let colors = get_theme_colors(theme);
+        let palette = theme.palette();
+
+        let mut mode = String::from(if is_dark_mode(theme) { "dark" } else { "light" });
+        if is_high_contrast(theme) {
+            mode.push_str(", high contrast");
+        }
+        if is_oled(theme) {
+            mode.push_str(", oled");
+        }
+        if is_sepia(theme) {
+            mode.push_str(", sepia");
+        }
+
+        let mut description = String::new();
+        let _ = writeln!(description, "theme: {theme}");
+        let _ = writeln!(description, "mode: {mode}");
+        let _ = writeln!(description, "accent: {}", colors.blue);
+
+        let _ = writeln!(description, "palette:");
+        for (role, color) in [
+            ("background", palette.background),
+            ("text", palette.text),
+            ("primary", palette.primary),
+            ("success", palette.success),
+            ("danger", palette.danger),
+            ("warning", palette.warning),
+        ] {
+            let _ = writeln!(description, "  {role:<20} {color}");
+        }
+
+        let _ = writeln!(description, "colors:");
+        for (role, color) in colors.entries() {
+            let _ = writeln!(description, "  {role:<20} {color}");
+        }
+
+        description
+    }
+
+    /// Get a fingerprint of a theme, for invalidating caches keyed by theme
+    ///
+    /// Changes whenever the theme name or any resolved color changes. Use
+    /// `ModernProvider::theme_fingerprint` to include a config as well.
+    pub fn theme_fingerprint(theme: &Theme) -> u64 {
+        ModernProvider::default().theme_fingerprint(theme)
+    }
+
+    /// Check a theme's text and background pairs against WCAG AA
+    ///
+    /// Returns every pair that falls short, for checking custom themes in
+    /// your own tests or printing during development (each issue displays
+    /// as e.g. "placeholder on input_bg: 2.90:1, needs 3.0:1"). Use
+    /// `ModernProvider::audit_contrast` to include a brand accent.
+    pub fn audit_contrast(theme: &Theme) -> Vec<ContrastIssue> {
+        ModernProvider::default().audit_contrast(theme)
+    }
+
+    /// Get the palette of the light or dark Modern theme
+    ///
+    /// The exact palette `Modern::theme` uses, for iced APIs that take a raw
+    /// `Palette` (e.g. `Theme::custom_with_fn` or `Extended::generate`).
+    pub fn palette(dark_mode: bool) -> iced::theme::Palette {
+        modern_palette(dark_mode, None)
+    }
+
+    /// Create a light Modern-styled theme
+    pub fn light_theme() -> Theme {
+        Self::theme(false)
+    }
+
+    /// Create a dark Modern-styled theme
+    pub fn dark_theme() -> Theme {
+        Self::theme(true)
+    }
+
+    /// Get the accent colors of a theme as named swatches
+    ///
+    /// Useful for rendering a row of swatches in a settings screen. The
+    /// order is stable: blue, green, red, orange, purple, teal, pink, indigo.
+    pub fn swatches(theme: &Theme) -> Vec<(&'static str, Color)> {
+        let colors = get_theme_colors(theme);
+
+        vec![
+            ("Blue", colors.blue),
+            ("Green", colors.green),
+            ("Red", colors.red),
+            ("Orange", colors.orange),
+            ("Purple", colors.purple),
+            ("Teal", colors.teal),
+            ("Pink", colors.pink),
+            ("Indigo", colors.indigo),
+        ]
+    }
+
+    // Additional button styles using more Modern colors
+
+    /// Get a teal button style (cyan-blue)
+    pub fn teal_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().teal_button()
+    }
+
+    /// Get an indigo button style (blue-purple)
+    pub fn indigo_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().indigo_button()
+    }
+
+    /// Get a purple button style
+    pub fn purple_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().purple_button()
+    }
+
+    /// Get a pink button style
+    pub fn pink_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().pink_button()
+    }
+
+    /// Get an amber button style (a softer warning with guaranteed readable text)
+    pub fn amber_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().amber_button()
+    }
+
+    /// Get an Modern-style gray button (neutral, subdued appearance)
+    pub fn gray_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().gray_button()
+    }
+
+    /// Get an Modern-style ghost button (translucent gray fill that works on any surface)
+    ///
+    /// Steps through the fill levels: tertiary at rest, secondary on hover
+    /// and primary when pressed.
+    pub fn ghost_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().ghost_button()
+    }
+
+    /// Get an Modern-style tinted button (semi-transparent colored background)
+    pub fn tinted_button<'a>(
+        color_variant: TintedButtonColor,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::button(style::Button::Tinted(color_variant))
+    }
+
+    /// Get an Modern-style blue tinted button
+    pub fn blue_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::tinted_button(TintedButtonColor::Blue)
+    }
+
+    /// Get an Modern-style green tinted button
+    pub fn green_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::tinted_button(TintedButtonColor::Green)
+    }
+
+    /// Get an Modern-style red tinted button
+    pub fn red_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::tinted_button(TintedButtonColor::Red)
+    }
+
+    /// Get an Modern-style orange tinted button
+    pub fn orange_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::tinted_button(TintedButtonColor::Orange)
+    }
+
+    /// Get an Modern-style purple tinted button
+    pub fn purple_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::tinted_button(TintedButtonColor::Purple)
+    }
+
+    /// Get an Modern-style teal tinted button
+    pub fn teal_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::tinted_button(TintedButtonColor::Teal)
+    }
+
+    /// Get an Modern-style indigo tinted button
+    pub fn indigo_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::tinted_button(TintedButtonColor::Indigo)
+    }
+
+    /// Get an Modern-style pink tinted button
+    pub fn pink_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        Self::tinted_button(TintedButtonColor::Pink)
+    }
+
+    /// Get a button style in the given color at the given emphasis
+    ///
+    /// Expresses intent rather than a specific look: high emphasis is the
+    /// filled button of that color (e.g. `success_button` for green),
+    /// medium the tinted button and low an outlined button like
+    /// `secondary_button`, drawn in the color.
+    pub fn button_emphasis<'a>(
+        color: TintedButtonColor,
+        emphasis: ButtonEmphasis,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        move |theme, status| match emphasis {
+            ButtonEmphasis::High => match color {
+                TintedButtonColor::Blue => Self::primary_button()(theme, status),
+                TintedButtonColor::Green => Self::success_button()(theme, status),
+                TintedButtonColor::Red => Self::danger_button()(theme, status),
+                TintedButtonColor::Orange => Self::warning_button()(theme, status),
+                TintedButtonColor::Purple => Self::purple_button()(theme, status),
+                TintedButtonColor::Teal => Self::teal_button()(theme, status),
+                TintedButtonColor::Pink => Self::pink_button()(theme, status),
+                TintedButtonColor::Indigo => Self::indigo_button()(theme, status),
+            },
+            ButtonEmphasis::Medium => Self::tinted_button(color)(theme, status),
+            ButtonEmphasis::Low => {
+                // The secondary button is an accent outline, so recolor its accent
+                let config = ModernConfig {
+                    accent: Some(tinted_color(&get_theme_colors(theme), color)),
+                    ..ModernConfig::default()
+                };
+
+                button_style(theme, &style::Button::Secondary, status, &config)
+            }
+        }
+    }
+
+    /// Size variants for buttons (small, medium, large)
+    pub fn sized_button<'a>(
+        style_fn: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
+        size: ButtonSize,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        move |theme, status| {
+            let mut base_style = style_fn(theme, status);
+
+            // Modify the border radius based on size
+            base_style.border = Border {
+                radius: match size {
+                    ButtonSize::Small => (CORNER_RADIUS * 0.8).into(),
+                    ButtonSize::Medium => CORNER_RADIUS.into(),
+                    ButtonSize::Large => (CORNER_RADIUS * 1.2).into(),
+                },
+                ..base_style.border
+            };
+
+            base_style
+        }
+    }
+
+    /// Get the minimum touch target size, 44px
+    ///
+    /// Styles can't size widgets, so apply it yourself: give icon-only
+    /// buttons `.width(Modern::min_touch_size()).height(Modern::min_touch_size())`
+    /// and labeled ones `Modern::touch_button_padding` for the height.
+    pub fn min_touch_size() -> f32 {
+        MIN_TOUCH_SIZE
+    }
+
+    /// Get the spacing of a step of the spacing scale, in pixels
+    ///
+    /// Use it for `padding` and `spacing` so layouts share the 4/8/16/24/32
+    /// scale alongside the widget corner radii.
+    pub fn spacing(level: SpacingLevel) -> f32 {
+        match level {
+            SpacingLevel::ExtraSmall => spacing::SPACING_XS,
+            SpacingLevel::Small => spacing::SPACING_S,
+            SpacingLevel::Medium => spacing::SPACING_M,
+            SpacingLevel::Large => spacing::SPACING_L,
+            SpacingLevel::ExtraLarge => spacing::SPACING_XL,
+        }
+    }
+
+    /// Get button padding that makes a single-line label at least 44px tall
+    ///
+    /// `font_size` is the label's text size in pixels, assuming iced's
+    /// default 1.3 line height. Horizontal padding is 16px; use
+    /// `.width(Modern::min_touch_size())` or wider for very short labels.
+    pub fn touch_button_padding(font_size: f32) -> Padding {
+        let line_height = font_size * 1.3;
+        let vertical = ((MIN_TOUCH_SIZE - line_height) / 2.0).ceil().max(0.0);
+
+        Padding {
+            top: vertical,
+            bottom: vertical,
+            left: 16.0,
+            right: 16.0,
+        }
+    }
+
+    /// Get the color of an activity spinner drawn inside a button
+    ///
+    /// Pass `on_accent: true` for buttons filled with the accent, like
+    /// `primary_button`, to get the same color as their label. Tinted,
+    /// outlined and link buttons sit on a faint or transparent fill, so they
+    /// get the accent itself.
+    pub fn spinner_color(theme: &Theme, on_accent: bool) -> Color {
+        let colors = get_theme_colors(theme);
+
+        if on_accent {
+            accent_label_color(colors.blue)
+        } else {
+            colors.blue
+        }
+    }
+
+    /// Get the recommended checkbox and radio `.size()` for a size variant
+    ///
+    /// 14px for small, iced's default 16px for medium and 20px for large.
+    /// Pair it with `sized_checkbox` or `sized_radio` of the same size.
+    pub fn control_size(size: ButtonSize) -> f32 {
+        match size {
+            ButtonSize::Small => 14.0,
+            ButtonSize::Medium => 16.0,
+            ButtonSize::Large => 20.0,
+        }
+    }
+
+    /// Size variants for checkboxes (small, medium, large)
+    ///
+    /// Scales the border width and corner radius with the box. Styles can't
+    /// size widgets, so also set `.size(Modern::control_size(size))`.
+    pub fn sized_checkbox<'a>(
+        size: ButtonSize,
+    ) -> impl Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a {
+        ModernProvider::default().sized_checkbox(size)
+    }
+
+    /// Size variants for radio buttons (small, medium, large)
+    ///
+    /// Scales the ring width with the circle. Styles can't size widgets, so
+    /// also set `.size(Modern::control_size(size))`.
+    pub fn sized_radio<'a>(
+        size: ButtonSize,
+    ) -> impl Fn(&Theme, radio::Status) -> radio::Style + 'a {
+        ModernProvider::default().sized_radio(size)
+    }
+
+    /// Create a "selected" version of any button style
+    ///
+    /// This function takes any button style and creates a modified version where
+    /// the default appearance matches what would normally be the pressed state.
+    /// This is perfect for navigation items to show which item is currently selected.
+    ///
+    /// Text-only buttons (like `plain_button` and `link_button`) have no
+    /// background to press, so they are shown selected with a light tint of
+    /// their text color instead.
+    pub fn selected_button_style<'a>(
+        base_style_fn: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        move |theme, status| {
+            let is_dark = is_dark_mode(theme);
+
+            match status {
+                ButtonStatus::Active => {
+                    let base_style = base_style_fn(theme, ButtonStatus::Active);
+
+                    let is_text_only = match base_style.background {
+                        None => true,
+                        Some(Background::Color(color)) => color.a == 0.0,
+                        Some(Background::Gradient(_)) => false,
+                    };
+
+                    if is_text_only {
+                        // Tint the background with the text color to mark the selection
+                        button::Style {
+                            background: Some(Background::Color(
+                                base_style.text_color.scale_alpha(0.12),
+                            )),
+                            border: Border {
+                                radius: SMALL_CORNER_RADIUS.into(),
+                                ..base_style.border
+                            },
+                            ..base_style
+                        }
+                    } else {
+                        // For the active state, use what would normally be the pressed state
+                        button_pressed_style(base_style, is_dark, &ModernConfig::default())
+                    }
+                }
+                // For other states, use the original style function
+                _ => base_style_fn(theme, status),
+            }
+        }
+    }
+
+    /// Brighten a button style for a tap flash
+    ///
+    /// Styles are stateless, so the caller drives the timing: start
+    /// `intensity` at around 0.4 when the button is tapped and decay it to
+    /// 0.0 over a few frames (e.g. from a `window::frames` subscription).
+    /// Disabled buttons are never flashed.
+    pub fn flash_button<'a>(
+        base_style_fn: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
+        intensity: f32,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        move |theme, status| {
+            let base_style = base_style_fn(theme, status);
+
+            if status == ButtonStatus::Disabled || intensity <= 0.0 {
+                base_style
+            } else {
+                button_flash_style(base_style, intensity)
+            }
+        }
+    }
+
+    /// Make any button style look pushed in while pressed
+    ///
+    /// Iced styles can't scale a widget, so instead the pressed state
+    /// rounds the corners 1px more and halves the shadow, implying depth
+    /// without any layout change. Square corners stay square so joined
+    /// buttons (e.g. in input groups) keep their seams. Other states are
+    /// passed through unchanged.
+    pub fn pressed_inset_button<'a>(
+        base_style_fn: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        move |theme, status| {
+            let base_style = base_style_fn(theme, status);
+
+            if status != ButtonStatus::Pressed {
+                return base_style;
+            }
+
+            let inset = |radius: f32| if radius > 0.0 { radius + 1.0 } else { radius };
+            let radius = base_style.border.radius;
+
+            button::Style {
+                border: Border {
+                    radius: border::Radius {
+                        top_left: inset(radius.top_left),
+                        top_right: inset(radius.top_right),
+                        bottom_right: inset(radius.bottom_right),
+                        bottom_left: inset(radius.bottom_left),
+                    },
+                    ..base_style.border
+                },
+                shadow: Shadow {
+                    color: base_style.shadow.color.scale_alpha(0.5),
+                    offset: base_style.shadow.offset * 0.5,
+                    blur_radius: base_style.shadow.blur_radius * 0.5,
+                },
+                ..base_style
+            }
+        }
+    }
+
+    /// Get a tab-bar button style
+    ///
+    /// Inactive tabs are transparent with regular text. The active tab uses
+    /// the accent for its text and an accent border; Iced borders are uniform,
+    /// so for a bottom-only underline pair an unbordered tab with
+    /// `Modern::custom_rule`.
+    pub fn tab_button<'a>(active: bool) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().tab_button(active)
+    }
+
+    /// Get a sidebar navigation item button style
+    ///
+    /// Unselected items are transparent with regular text; the selected item
+    /// gets a light accent fill with accent text. Both tint on hover.
+    pub fn sidebar_item_button<'a>(
+        selected: bool,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().sidebar_item_button(selected)
+    }
+
+    /// Get a primary button style for the trailing part of an input group
+    ///
+    /// Only the right corners are rounded so the button joins seamlessly with
+    /// an adjacent `input_group_input` on its left.
+    pub fn input_group_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().input_group_button()
+    }
+
+    // Container variants
+
+    /// Get a container with separator line style
+    pub fn separated_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().separated_container()
     }
 
-    /// Get an Modern-style green tinted button
-    pub fn green_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::tinted_button(TintedButtonColor::Green)
+    /// Get a container style framing its content with a border on all sides
+    ///
+    /// No background and no shadow, with the theme's text color, so it can
+    /// frame arbitrary content. `radius` is the corner radius in pixels.
+    pub fn bordered_container<'a>(
+        color: Color,
+        width: f32,
+        radius: f32,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().bordered_container(color, width, radius)
     }
 
-    /// Get an Modern-style red tinted button
-    pub fn red_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::tinted_button(TintedButtonColor::Red)
+    /// Get a branded container with accent color border
+    pub fn accent_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().accent_container()
     }
 
-    /// Get an Modern-style orange tinted button
-    pub fn orange_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::tinted_button(TintedButtonColor::Orange)
+    /// Get a faintly tinted container style for the body of a blockquote or callout
+    ///
+    /// Iced borders have a single width for all sides, so the thick left bar
+    /// is drawn by a separate `Modern::accent_bar` container. Put both in a
+    /// row, the bar first, e.g. `row![container(space::horizontal()).width(4)
+    /// .height(Length::Fill).style(Modern::accent_bar(color)), container(note)
+    /// .padding(12).style(Modern::accent_bar_container(color))]` inside a
+    /// container with `Length::Shrink` height. On its own the body still
+    /// reads as a tinted note, with square left corners.
+    pub fn accent_bar_container<'a>(
+        color: TintedButtonColor,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().accent_bar_container(color)
     }
 
-    /// Get an Modern-style purple tinted button
-    pub fn purple_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::tinted_button(TintedButtonColor::Purple)
+    /// Get the solid bar drawn left of an `accent_bar_container`
+    ///
+    /// Style a narrow (3-4px wide), full-height container with it.
+    pub fn accent_bar<'a>(color: TintedButtonColor) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().accent_bar(color)
     }
 
-    /// Get an Modern-style teal tinted button
-    pub fn teal_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::tinted_button(TintedButtonColor::Teal)
+    /// Get a container style for a file drop zone
+    ///
+    /// Pass `active` while a file hovers over the zone: the border turns
+    /// from the separator color to the accent and the zone gets a faint
+    /// accent tint. Iced borders are always solid, so this is a 2px solid
+    /// border; for a true dashed outline draw it yourself on a `canvas`
+    /// with the colors from `Modern::drop_zone_colors`.
+    pub fn drop_zone_container<'a>(active: bool) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().drop_zone_container(active)
     }
 
-    /// Get an Modern-style indigo tinted button
-    pub fn indigo_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::tinted_button(TintedButtonColor::Indigo)
+    /// Get the border and background colors of a file drop zone
+    ///
+    /// The same colors `drop_zone_container` uses, for drawing a dashed
+    /// border on a canvas. The background is transparent while inactive.
+    pub fn drop_zone_colors(theme: &Theme, active: bool) -> (Color, Color) {
+        drop_zone_colors(&get_theme_colors(theme), active)
     }
 
-    /// Get an Modern-style pink tinted button
-    pub fn pink_tinted_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        Self::tinted_button(TintedButtonColor::Pink)
+    /// Get a container style for text laid over an image, with a dark scrim
+    ///
+    /// Stack the container on top of the image and put the text inside it.
+    /// `Bottom` and `Top` fade from transparent at 40% of the height to 60%
+    /// black at that edge; `Full` is an even 40% black. Text is white in
+    /// every theme, since the scrim is dark regardless of the theme mode.
+    pub fn image_scrim_container<'a>(
+        direction: GradientDirection,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().image_scrim_container(direction)
     }
 
-    /// Size variants for buttons (small, medium, large)
-    pub fn sized_button<'a>(
-        style_fn: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
-        size: ButtonSize,
-    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| {
-            let mut base_style = style_fn(theme, status);
-
-            // Modify the border radius based on size
-            base_style.border = Border {
-                radius: match size {
-                    ButtonSize::Small => (CORNER_RADIUS * 0.8).into(),
-                    ButtonSize::Medium => CORNER_RADIUS.into(),
-                    ButtonSize::Large => (CORNER_RADIUS * 1.2).into(),
-                },
-                ..base_style.border
-            };
-
-            base_style
-        }
+    /// Get a container style marking the selected row of a list
+    ///
+    /// A 15% accent tint behind normal-strength text, for lists whose rows
+    /// aren't buttons (wrap each row in a container and swap this style in
+    /// for the chosen one). Corners are square so a selected row sits flush
+    /// between its neighbors inside a grouped list.
+    pub fn selected_row_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().selected_row_container()
     }
 
-    /// Create a "selected" version of any button style
+    /// Get `n` distinguishable series colors for charts drawn on the theme
     ///
-    /// This function takes any button style and creates a modified version where
-    /// the default appearance matches what would normally be the pressed state.
-    /// This is perfect for navigation items to show which item is currently selected.
-    pub fn selected_button_style<'a>(
-        base_style_fn: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
-    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| {
-            let is_dark = is_dark_mode(theme);
+    /// The first eight are the theme's accents in `TintedButtonColor`
+    /// order (blue, green, red, orange, purple, teal, pink, indigo). Further
+    /// colors step around the hue wheel by the golden angle, at the accents'
+    /// average saturation and lightness, skipping hues within 20° of any
+    /// color of similar lightness. Once the hue wheel is full the next
+    /// colors move to a darker, then lighter lightness tier, so at least the
+    /// first 48 colors keep that separation (past that it is halved). The
+    /// order is stable: the first `k` colors are the same for any `n >= k`,
+    /// so series keep their color as more are added.
+    pub fn categorical_colors(theme: &Theme, n: usize) -> Vec<Color> {
+        // Lightness offsets of successive tiers, each far enough apart to tell apart
+        const TIERS: [f32; 5] = [0.0, -0.12, 0.12, -0.24, 0.24];
+        const GOLDEN_ANGLE: f32 = 137.508;
+
+        let colors = get_theme_colors(theme);
+        let mut series: Vec<Color> = TintedButtonColor::all()
+            .iter()
+            .map(|accent| tinted_color(&colors, *accent))
+            .collect();
+
+        let count = series.len() as f32;
+        let saturation = series.iter().map(|color| to_hsl(*color).1).sum::<f32>() / count;
+        let lightness = series.iter().map(|color| to_hsl(*color).2).sum::<f32>() / count;
+        let hue_distance = |a: f32, b: f32| {
+            let distance = (a - b).rem_euclid(360.0);
+            distance.min(360.0 - distance)
+        };
+
+        let mut hue = to_hsl(colors.blue).0;
+        let mut tier = 0;
+        let mut min_hue_distance = 20.0;
+        let mut misses = 0;
+
+        while series.len() < n {
+            hue = (hue + GOLDEN_ANGLE).rem_euclid(360.0);
+            let tier_lightness = (lightness + TIERS[tier]).clamp(0.2, 0.8);
+
+            let distinct = series.iter().all(|color| {
+                let (other_hue, _, other_lightness) = to_hsl(*color);
+
+                hue_distance(hue, other_hue) >= min_hue_distance
+                    || (tier_lightness - other_lightness).abs() >= 0.1
+            });
+
+            if distinct {
+                series.push(from_hsl(hue, saturation, tier_lightness));
+                misses = 0;
+            } else {
+                misses += 1;
 
-            match status {
-                ButtonStatus::Active => {
-                    // For the active state, use what would normally be the pressed state
-                    let base_style = base_style_fn(theme, ButtonStatus::Active);
-                    button_pressed_style(base_style, is_dark)
+                // No gap left on this tier: move on, relaxing the spacing after the last one
+                if misses > 60 {
+                    misses = 0;
+                    tier += 1;
+
+                    if tier == TIERS.len() {
+                        tier = 0;
+                        min_hue_distance /= 2.0;
+                    }
                 }
-                // For other states, use the original style function
-                _ => base_style_fn(theme, status),
             }
         }
+
+        series.truncate(n);
+        series
     }
 
-    // Container variants
+    /// Get a container style for a keyboard shortcut key cap (e.g. "⌘" or "K")
+    ///
+    /// A small raised chip with a hairline border and a 1px bottom shadow
+    /// like a physical key. Fill it with small text styled with
+    /// `Modern::secondary_text` and pad it by about `[2, 6]`.
+    pub fn keycap_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().keycap_container()
+    }
 
-    /// Get a container with separator line style
-    pub fn separated_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        move |theme| {
-            let colors = get_theme_colors(theme);
+    /// Get a toolbar container style
+    pub fn toolbar_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().toolbar_container()
+    }
 
-            container::Style {
-                text_color: Some(colors.text),
-                background: Some(Background::Color(colors.background)),
-                border: Border {
-                    radius: 0.0.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow::default(),
-                snap: true,
-            }
-        }
+    /// Get a floating panel container style
+    pub fn floating_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().floating_container()
     }
 
-    /// Get a branded container with accent color border
-    pub fn accent_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        move |theme| {
-            let colors = get_theme_colors(theme);
+    /// Get a container filled with an arbitrary background color
+    ///
+    /// Uses the theme's text color unless it would be unreadable on `color`
+    /// (below AA contrast), in which case black or white is picked instead.
+    pub fn filled_container<'a>(color: Color) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().filled_container(color)
+    }
 
-            container::Style {
-                text_color: Some(colors.text),
-                background: Some(Background::Color(colors.background)),
-                border: Border {
-                    radius: 8.0.into(),
-                    width: 2.0,
-                    color: colors.blue,
-                },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.1,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 2.0),
-                    blur_radius: 4.0,
-                },
-                snap: true,
-            }
+    /// Get a shadow for the given elevation, cast in the given direction
+    ///
+    /// Elevation 1.0 matches card containers (2px offset, 8px blur); offset,
+    /// blur and opacity grow with elevation, opacity capping at 0.3.
+    pub fn elevation_shadow(elevation: f32, direction: ShadowDirection) -> Shadow {
+        let elevation = elevation.max(0.0);
+        let distance = 2.0 * elevation;
+
+        let offset = match direction {
+            ShadowDirection::Down => Vector::new(0.0, distance),
+            ShadowDirection::Up => Vector::new(0.0, -distance),
+            ShadowDirection::Left => Vector::new(-distance, 0.0),
+            ShadowDirection::Right => Vector::new(distance, 0.0),
+        };
+
+        Shadow {
+            color: Color {
+                a: (0.1 * elevation).min(0.3),
+                ..Color::BLACK
+            },
+            offset,
+            blur_radius: 8.0 * elevation,
         }
     }
 
-    /// Get a toolbar container style
-    pub fn toolbar_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        move |theme| {
-            let colors = get_theme_colors(theme);
+    /// Get a filled container whose shadow points in a chosen direction
+    ///
+    /// Use `ShadowDirection::Up` for dropups and bottom-anchored overlays,
+    /// where a downward shadow would fall onto the element they belong to.
+    /// Text color is picked as in `Modern::filled_container`.
+    pub fn shadowed_container<'a>(
+        background: Color,
+        direction: ShadowDirection,
+        elevation: f32,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().shadowed_container(background, direction, elevation)
+    }
 
-            container::Style {
-                text_color: Some(colors.text),
-                background: Some(Background::Color(colors.system_bg)),
-                border: Border {
-                    radius: 0.0.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.05,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-                snap: true,
-            }
-        }
+    /// Get a focus ring container style for keyboard-focused form controls
+    ///
+    /// Iced's checkbox and radio statuses don't expose keyboard focus, so
+    /// their styles can't draw a focus outline themselves. Wrap the control in
+    /// a container with this style while it has focus (e.g. via
+    /// `conditional_container_style`) for the same accent outline that
+    /// focused text inputs use.
+    pub fn focus_ring_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().focus_ring_container()
     }
 
-    /// Get a floating panel container style
-    pub fn floating_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        move |theme| {
+    /// Get a button style that draws a focus ring while `focused`
+    ///
+    /// Iced's button status doesn't encode keyboard focus, so track it in
+    /// your own state and pass it in. The ring is layered on top of the
+    /// regular style for the current status: a 2px accent border plus a
+    /// soft accent glow outside the button, which stays visible on
+    /// accent-filled buttons. Background and text still follow hover and
+    /// press, so a focused button that is also hovered shows both. Disabled
+    /// buttons never show the ring.
+    pub fn focus_ring_button<'a>(
+        style: style::Button,
+        focused: bool,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        move |theme, status| {
+            let base_style = button_style(theme, &style, status, &ModernConfig::default());
+
+            if !focused || status == ButtonStatus::Disabled {
+                return base_style;
+            }
+
             let colors = get_theme_colors(theme);
 
-            container::Style {
-                text_color: Some(colors.text),
-                background: Some(Background::Color(colors.card_bg)),
+            button::Style {
                 border: Border {
-                    radius: 10.0.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+                    width: 2.0,
+                    color: colors.blue,
+                    ..base_style.border
                 },
                 shadow: Shadow {
-                    color: Color {
-                        a: 0.25,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 4.0),
-                    blur_radius: 16.0,
+                    color: colors.blue.scale_alpha(0.4),
+                    offset: Vector::ZERO,
+                    blur_radius: 6.0,
                 },
-                snap: true,
+                ..base_style
             }
         }
     }
 
+    /// Get a container style for the handle of a custom split pane
+    ///
+    /// Fill a thin container (1-4px across) between the panes with it: the
+    /// handle is a separator line at rest, blends halfway to the accent on
+    /// hover and turns solid accent while being dragged. Use it for split
+    /// layouts built without `pane_grid`.
+    pub fn split_handle_container<'a>(
+        hovered: bool,
+        dragging: bool,
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        ModernProvider::default().split_handle_container(hovered, dragging)
+    }
+
     /// Get a danger tooltip container style with error styling
     pub fn danger_tooltip_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        move |theme| {
-            let _colors = get_theme_colors(theme);
-
-            // Determine if dark mode
-            let dark_mode = is_dark_mode(theme);
-
-            container::Style {
-                text_color: Some(if dark_mode {
-                    Color::from_rgb(1.0, 0.6, 0.6) // lighter red for better contrast
-                } else {
-                    Color::from_rgb(0.7, 0.0, 0.0) // slightly darker red for better readability
-                }),
-                background: Some(Background::Color(if dark_mode {
-                    Color {
-                        r: 0.4,
-                        g: 0.1,
-                        b: 0.1,
-                        a: 1.0,
-                    } // More saturated dark red
-                } else {
-                    Color {
-                        r: 1.0,
-                        g: 0.92,
-                        b: 0.92,
-                        a: 1.0,
-                    } // softer, clearer red
-                })),
-                border: Border {
-                    radius: 6.0.into(),
-                    width: 1.0,
-                    color: if dark_mode {
-                        Color::from_rgb(0.8, 0.3, 0.3)
-                    } else {
-                        Color::from_rgb(0.9, 0.6, 0.6)
-                    },
-                },
-                shadow: Shadow {
-                    color: Color {
-                        r: 0.0,
-                        g: 0.0,
-                        b: 0.0,
-                        a: 0.15,
-                    },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 3.0,
-                },
-                snap: true,
-            }
-        }
+        ModernProvider::default().danger_tooltip_container()
     }
 
     /// Get a warning tooltip container style with warning styling
     pub fn warning_tooltip_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
-        move |theme| {
-            let colors = get_theme_colors(theme);
-
-            container::Style {
-                text_color: Some(colors.orange), // Orange text for warnings
-                background: Some(Background::Color(if is_dark_mode(theme) {
-                    // Darker theme - subtle orange-brown background
-                    Color {
-                        r: 0.3,
-                        g: 0.15,
-                        b: 0.0,
-                        a: 0.7,
-                    }
-                } else {
-                    // Light theme - very subtle light orange background
-                    Color {
-                        r: 1.0,
-                        g: 0.96,
-                        b: 0.9,
-                        a: 1.0,
-                    }
-                })),
-                border: Border {
-                    radius: 6.0.into(), // Slightly rounded corners
-                    width: 1.0,
-                    color: colors.orange, // Orange border to match the warning theme
-                },
-                shadow: Shadow {
-                    color: Color {
-                        a: 0.1,
-                        ..Color::BLACK
-                    },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-                snap: true,
-            }
-        }
+        ModernProvider::default().warning_tooltip_container()
     }
 
     /// Dynamically choose between danger, warning and standard tooltip container styles
@@ -1127,8 +3696,51 @@ impl Modern {
         }
     }
 
+    /// Get the recommended padding for tooltip containers
+    ///
+    /// Style functions can't set padding, so apply this to the tooltip's
+    /// container alongside `danger_tooltip_container`,
+    /// `warning_tooltip_container` or `conditional_tooltip_container`:
+    /// 4px vertically and 8px horizontally keeps tooltips compact and
+    /// consistently sized across an app.
+    pub fn tooltip_padding() -> Padding {
+        Padding::from([4, 8])
+    }
+
     // Text input variants
 
+    /// Get the recommended padding for Modern-style text inputs
+    ///
+    /// Style functions can't set padding, so use this on the widget itself.
+    /// Single-line inputs get 7px vertically which, with the 1px border (2px
+    /// when focused), centers a 14-16px line; multiline editors get a bit
+    /// more room so the first and last lines don't touch the border.
+    pub fn input_padding(multiline: bool) -> Padding {
+        if multiline {
+            Padding::from([10, 12])
+        } else {
+            Padding::from([7, 10])
+        }
+    }
+
+    /// Get the recommended text input padding for right-to-left layouts
+    ///
+    /// `input_padding` mirrored horizontally. The default padding happens to
+    /// be symmetric, but using this in RTL layouts keeps them correct should
+    /// it change. The `icon` color role is the same in both directions.
+    pub fn input_padding_rtl(multiline: bool) -> Padding {
+        Self::mirror_padding(Self::input_padding(multiline))
+    }
+
+    /// Swap the left and right sides of a padding, for right-to-left layouts
+    pub fn mirror_padding(padding: Padding) -> Padding {
+        Padding {
+            left: padding.right,
+            right: padding.left,
+            ..padding
+        }
+    }
+
     /// Get a search input style with rounded corners
     pub fn search_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
         move |theme, status| {
@@ -1152,6 +3764,7 @@ impl Modern {
                 TextInputStatus::Hovered => base_style,
                 TextInputStatus::Focused { is_hovered: _ } => text_input::Style {
                     background: Background::Color(colors.tertiary_background),
+                    placeholder: colors.placeholder.scale_alpha(FOCUSED_PLACEHOLDER_ALPHA),
                     ..base_style
                 },
                 TextInputStatus::Disabled => text_input::Style {
@@ -1163,6 +3776,24 @@ impl Modern {
         }
     }
 
+    /// Get the color of the clear ("x") icon of a search or clearable input
+    ///
+    /// The subtle placeholder color, so the icon recedes until needed.
+    /// `Modern::clear_icon_button` brightens it to the text color on hover.
+    pub fn clear_icon_color(theme: &Theme) -> Color {
+        get_theme_colors(theme).placeholder
+    }
+
+    /// Get a ghost button style for the clear icon of a search input
+    ///
+    /// Iced text inputs can't hold a button, so overlay a small icon button
+    /// (e.g. in a `stack` at the trailing edge, padded by about 4px) with
+    /// this style. It has no background; the icon is `clear_icon_color` at
+    /// rest and full-strength text on hover and press.
+    pub fn clear_icon_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        ModernProvider::default().clear_icon_button()
+    }
+
     /// Get an inline text input style with bottom border only
     pub fn inline_text_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
         move |theme, status| {
@@ -1196,6 +3827,7 @@ impl Modern {
                         width: 2.0,
                         ..base_style.border
                     },
+                    placeholder: colors.placeholder.scale_alpha(FOCUSED_PLACEHOLDER_ALPHA),
                     ..base_style
                 },
                 TextInputStatus::Disabled => text_input::Style {
@@ -1210,13 +3842,21 @@ impl Modern {
         }
     }
 
+    /// Get a text input style for the leading part of an input group
+    ///
+    /// Only the left corners are rounded so the input joins seamlessly with
+    /// an adjacent `input_group_button` on its right.
+    pub fn input_group_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        ModernProvider::default().input_group_input()
+    }
+
     /// Get an modern danger theme for text inputs with validation errors
     pub fn danger_text_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
         move |theme, status| {
             let colors = get_theme_colors(theme);
 
             // Start with base text input style
-            let base_style = text_input_style(theme, status);
+            let base_style = text_input_style(theme, status, &ModernConfig::default());
 
             // Override with error styling
             text_input::Style {
@@ -1248,28 +3888,53 @@ impl Modern {
         }
     }
 
-    /// Dynamically choose between danger, warning and inline text input styles
-    pub fn conditional_text_input<'a>(
-        validation_state: ValidationState,
-    ) -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
-        move |theme, status| {
-            match validation_state {
-                ValidationState::Error => {
-                    // Call the danger function and immediately apply it
-                    (Self::danger_text_input())(theme, status)
-                }
-                ValidationState::Warning => {
-                    // Call the warning function and immediately apply it
-                    (Self::warning_text_input())(theme, status)
-                }
-                ValidationState::Valid => {
-                    // Call the inline function and immediately apply it
-                    (Self::inline_text_input())(theme, status)
-                }
+    /// Get a text input style for read-only fields that display a value
+    ///
+    /// Read-only is not disabled: the value stays at full strength on a
+    /// `system_bg` fill with a separator border, and neither hover nor
+    /// focus change the border. Iced inputs without `on_input` report the
+    /// Disabled status, so this style looks the same in every status; keep
+    /// `Modern::text_input` for fields that are truly unavailable.
+    pub fn readonly_text_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        move |theme, _status| {
+            let colors = get_theme_colors(theme);
+            let base_style =
+                text_input_style(theme, TextInputStatus::Active, &ModernConfig::default());
+
+            text_input::Style {
+                background: Background::Color(colors.system_bg),
+                border: Border {
+                    color: colors.separator,
+                    ..base_style.border
+                },
+                ..base_style
             }
         }
     }
 
+    /// Dynamically choose between danger, warning and inline text input styles
+    ///
+    /// Use a provider with `ModernConfig::success_on_valid` to show a green
+    /// border for valid input instead of the plain inline style.
+    pub fn conditional_text_input<'a>(
+        validation_state: ValidationState,
+    ) -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        ModernProvider::default().conditional_text_input(validation_state)
+    }
+
+    /// Get a text input style reflecting how close a value is to its length limit
+    ///
+    /// Uses the inline style below 90% of `max`, the orange warning style
+    /// from 90% up to `max` and the red danger style once `current` exceeds
+    /// `max` (see `ValidationState::from_length`). `current` is usually the
+    /// value's character count, `value.chars().count()`.
+    pub fn length_limited_text_input<'a>(
+        current: usize,
+        max: usize,
+    ) -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        Self::conditional_text_input(ValidationState::from_length(current, max))
+    }
+
     // Simple conditional text_input if you don't need/want a warning state
     pub fn validated_text_input<'a>(
         has_error: bool,
@@ -1287,7 +3952,7 @@ impl Modern {
     pub fn warning_text_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
         move |theme, status| {
             let colors = get_theme_colors(theme);
-            let base_style = text_input_style(theme, status);
+            let base_style = text_input_style(theme, status, &ModernConfig::default());
 
             text_input::Style {
                 border: Border {
@@ -1300,6 +3965,48 @@ impl Modern {
         }
     }
 
+    /// Get an modern success theme for text inputs that validated successfully
+    pub fn success_text_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        move |theme, status| {
+            let colors = get_theme_colors(theme);
+            let base_style = text_input_style(theme, status, &ModernConfig::default());
+
+            text_input::Style {
+                border: Border {
+                    color: colors.green, // Green border for valid input
+                    width: 1.0,
+                    ..base_style.border
+                },
+                ..base_style
+            }
+        }
+    }
+
+    /// Get a text input style whose focus border uses the given accent
+    ///
+    /// For sections themed around a non-blue accent. The text selection is
+    /// tinted with the same accent; every other status is unchanged.
+    pub fn accent_text_input<'a>(
+        color: TintedButtonColor,
+    ) -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
+        move |theme, status| {
+            let config = ModernConfig {
+                accent: Some(tinted_color(&get_theme_colors(theme), color)),
+                ..ModernConfig::default()
+            };
+
+            text_input_style(theme, status, &config)
+        }
+    }
+
+    /// Get a readable text color for an arbitrary background
+    ///
+    /// Returns the palette's primary light-mode text (black) or dark-mode
+    /// text (white), whichever has the higher contrast against `bg`.
+    pub fn contrasting_text(bg: Color) -> Color {
+        on_color(bg)
+    }
+
     /// Get an Modern-style primary text style (main content text)
     pub fn primary_text<'a>() -> impl Fn(&Theme) -> text::Style + 'a {
         |theme| {
@@ -1333,6 +4040,32 @@ impl Modern {
         }
     }
 
+    /// Get a text style for metadata that should recede (timestamps, counts, sizes)
+    ///
+    /// Uses `tertiary_text`, the recommended color for overflow and meta
+    /// details in list rows. Iced draws a truncation ellipsis in the same
+    /// color as the rest of the text and can't style it separately, so
+    /// labels expected to truncate read calmer in this style too.
+    pub fn meta_text<'a>() -> impl Fn(&Theme) -> text::Style + 'a {
+        Self::tertiary_text()
+    }
+
+    /// Get a text style at the given emphasis level
+    ///
+    /// Fades the primary text color by the level's alpha, Material-style,
+    /// for data tables and other dense layouts that need finer steps than
+    /// `primary_text`, `secondary_text` and `tertiary_text`, which use
+    /// dedicated label colors instead.
+    pub fn emphasized_text<'a>(level: EmphasisLevel) -> impl Fn(&Theme) -> text::Style + 'a {
+        move |theme| {
+            let colors = get_theme_colors(theme);
+
+            text::Style {
+                color: Some(colors.text.scale_alpha(level.alpha())),
+            }
+        }
+    }
+
     /// Get an Modern-style link text style
     pub fn link_text<'a>() -> impl Fn(&Theme) -> text::Style + 'a {
         |theme| {
@@ -1344,6 +4077,75 @@ impl Modern {
         }
     }
 
+    /// Get an underlined, link-colored span for use in a `rich_text`
+    ///
+    /// Text styles can't underline, so this returns a span instead. Attach a
+    /// link with `.link(...)` and toggle the underline from your own hover
+    /// state if you only want it on hover.
+    pub fn link_text_underlined<'a, Link>(
+        theme: &Theme,
+        fragment: impl text::IntoFragment<'a>,
+    ) -> text::Span<'a, Link> {
+        let colors = get_theme_colors(theme);
+
+        text::Span::new(fragment).color(colors.link).underline(true)
+    }
+
+    /// Get a text style for prominent numeric values (e.g. in stat cards)
+    ///
+    /// Uses full-strength text; pair it with a large font size (28-34) and
+    /// `Modern::stat_label_text` for the caption beneath.
+    pub fn stat_text<'a>() -> impl Fn(&Theme) -> text::Style + 'a {
+        Self::primary_text()
+    }
+
+    /// Get a text style for the caption of a stat value
+    pub fn stat_label_text<'a>() -> impl Fn(&Theme) -> text::Style + 'a {
+        Self::secondary_text()
+    }
+
+    /// Get a text style for grouped settings section headers
+    ///
+    /// Text styles can't change case or size, so pass an uppercased label
+    /// with a small font size (around 12), e.g.
+    /// `text("NETWORK").size(12).style(Modern::form_section_header_text())`,
+    /// above a `form_section_container`.
+    pub fn form_section_header_text<'a>() -> impl Fn(&Theme) -> text::Style + 'a {
+        Self::secondary_text()
+    }
+
+    /// Get a text style for keyboard shortcut hints inside buttons (e.g. "⌘S")
+    ///
+    /// The regular text color at 60% opacity, so the hint sits behind the
+    /// label. Put it in the button content next to the label, e.g.
+    /// `row![text("Save"), space::horizontal(), text("⌘S").style(..)]`. Made
+    /// for plain, system and outlined buttons; use `shortcut_hint_text_for`
+    /// inside filled buttons.
+    pub fn shortcut_hint_text<'a>() -> impl Fn(&Theme) -> text::Style + 'a {
+        Self::shortcut_hint_text_for(style::Button::Plain)
+    }
+
+    /// Get a shortcut hint text style matched to a button style's label
+    ///
+    /// Dims the label color of `button` to 60%, so hints stay readable on
+    /// filled buttons such as `style::Button::Primary` too.
+    pub fn shortcut_hint_text_for<'a>(
+        button: style::Button,
+    ) -> impl Fn(&Theme) -> text::Style + 'a {
+        move |theme| {
+            let label = button_style(
+                theme,
+                &button,
+                ButtonStatus::Active,
+                &ModernConfig::default(),
+            );
+
+            text::Style {
+                color: Some(label.text_color.scale_alpha(0.6)),
+            }
+        }
+    }
+
     /// Get text styled with a specific color for both light and dark modes
     pub fn colored_text<'a>(
         light_color: Color,
@@ -1441,26 +4243,44 @@ impl Modern {
     /// Get an modern theme for combo boxes
     pub fn combo_box<'a>() -> impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a {
         // Use the same style as text_input, since combo_box uses TextInput under the hood
-        text_input_style
+        combo_box_style
     }
 
     /// Get a modern theme for combo box menus
     pub fn combo_box_menu<'a>() -> impl Fn(&Theme) -> menu::Style + 'a {
+        Self::menu_keyboard()
+    }
+
+    /// Get a Modern-style theme for dropdown menus (pick lists, combo boxes)
+    ///
+    /// Iced menus have a single selection style for both mouse hover and
+    /// keyboard navigation, so this default strikes a balance: a medium
+    /// accent tint with regular text. Apps that track the last input device
+    /// can swap between `menu_mouse` and `menu_keyboard` instead.
+    pub fn menu<'a>() -> impl Fn(&Theme) -> menu::Style + 'a {
+        |theme| {
+            let colors = get_theme_colors(theme);
+            menu_style(theme, colors.blue.scale_alpha(0.25), colors.text)
+        }
+    }
+
+    /// Get a menu style for mouse interaction, with a light hover tint
+    ///
+    /// The highlight only follows the pointer, so it stays subtle.
+    pub fn menu_mouse<'a>() -> impl Fn(&Theme) -> menu::Style + 'a {
         |theme| {
             let colors = get_theme_colors(theme);
+            menu_style(theme, colors.blue.scale_alpha(0.12), colors.text)
+        }
+    }
 
-            menu::Style {
-                text_color: colors.text,
-                background: Background::Color(colors.card_bg),
-                border: Border {
-                    radius: TINY_CORNER_RADIUS.into(),
-                    width: 1.0,
-                    color: colors.input_border,
-                },
-                selected_text_color: Color::WHITE,
-                selected_background: Background::Color(colors.blue),
-                shadow: Shadow::default(),
-            }
+    /// Get a menu style for keyboard navigation, with a solid accent selection
+    ///
+    /// With no pointer to follow, the selected option needs to stand out.
+    pub fn menu_keyboard<'a>() -> impl Fn(&Theme) -> menu::Style + 'a {
+        |theme| {
+            let colors = get_theme_colors(theme);
+            menu_style(theme, colors.blue, Color::WHITE)
         }
     }
 
@@ -1512,28 +4332,41 @@ impl Modern {
 }
 
 /// Modern design-inspired button style implementation
-fn button_style(theme: &Theme, class: &style::Button, status: ButtonStatus) -> button::Style {
-    let colors = get_theme_colors(theme);
+fn button_style(
+    theme: &Theme,
+    class: &style::Button,
+    status: ButtonStatus,
+    config: &ModernConfig,
+) -> button::Style {
+    let colors = get_theme_colors_with(theme, config);
     let is_dark = is_dark_mode(theme);
 
+    // Function to resolve a tinted fill, scaling the configured tint strength
+    let tint = |color: TintedButtonColor, strength: f32| {
+        let alpha = (config.tint_alpha * strength).clamp(0.0, 1.0);
+
+        solidify(
+            theme,
+            tinted_color(&colors, color).scale_alpha(alpha),
+            config,
+        )
+    };
+
     // Function to create the base Modern style with rounded corners
     let modern_base = |color: Color, text_color: Color| button::Style {
-        background: Some(Background::Color(color)),
+        background: Some(if config.button_sheen {
+            sheen(color)
+        } else {
+            Background::Color(color)
+        }),
         text_color,
         border: Border {
-            radius: CORNER_RADIUS.into(), // Modern's rounded corners
-            width: 0.0,                   // No border for filled buttons
+            radius: config.corner_radius.into(), // Modern's rounded corners
+            width: 0.0,                          // No border for filled buttons
             color: Color::TRANSPARENT,
         },
-        shadow: Shadow {
-            color: Color {
-                a: 0.1,
-                ..Color::BLACK
-            },
-            offset: Vector::new(0.0, 1.0),
-            blur_radius: 2.0,
-        },
-        snap: true,
+        shadow: scaled_shadow(BUTTON_SHADOW, config),
+        snap: config.snap,
     };
 
     // Function to create outlined style
@@ -1541,12 +4374,12 @@ fn button_style(theme: &Theme, class: &style::Button, status: ButtonStatus) -> b
         background: Some(Background::Color(Color::TRANSPARENT)),
         text_color,
         border: Border {
-            radius: CORNER_RADIUS.into(),
+            radius: config.corner_radius.into(),
             width: 1.0,
             color,
         },
         shadow: Shadow::default(),
-        snap: true,
+        snap: config.snap,
     };
 
     // Function to create a transparent button style (for links/text)
@@ -1555,22 +4388,28 @@ fn button_style(theme: &Theme, class: &style::Button, status: ButtonStatus) -> b
         text_color,
         border: Border::default(),
         shadow: Shadow::default(),
-        snap: true,
+        snap: config.snap,
     };
 
     // Base style based on button class
     let base_style = match class {
-        style::Button::Primary => modern_base(colors.blue, Color::WHITE),
+        style::Button::Primary => modern_base(colors.blue, accent_label_color(colors.blue)),
         style::Button::Secondary => outlined(colors.blue, colors.blue),
-        style::Button::Success => modern_base(colors.green, Color::WHITE),
-        style::Button::Warning => modern_base(
-            colors.orange,
-            if is_dark { Color::BLACK } else { Color::WHITE },
-        ),
-        style::Button::Danger => modern_base(colors.red, Color::WHITE),
+        style::Button::Success => modern_base(colors.green, accent_label_color(colors.green)),
+        style::Button::Warning => modern_base(colors.orange, on_color(colors.orange)),
+        style::Button::Danger => modern_base(colors.red, accent_label_color(colors.red)),
         style::Button::Link => transparent(colors.blue),
         style::Button::System => modern_base(colors.system_bg, colors.text),
         style::Button::Plain => transparent(colors.text),
+        style::Button::Tinted(color) => {
+            // Low-opacity fill with the full color for text, and a lighter shadow
+            let base_color = tinted_color(&colors, *color);
+
+            button::Style {
+                shadow: scaled_shadow(TINTED_BUTTON_SHADOW, config),
+                ..modern_base(tint(*color, 1.0), base_color)
+            }
+        }
     };
 
     // Adjust style based on status
@@ -1600,6 +4439,17 @@ fn button_style(theme: &Theme, class: &style::Button, status: ButtonStatus) -> b
             };
 
             match class {
+                style::Button::Link | style::Button::Plain if config.text_button_hover_bg => {
+                    // Faint background highlight, like macOS menu items
+                    button::Style {
+                        background: Some(Background::Color(colors.system_bg)),
+                        border: Border {
+                            radius: config.small_corner_radius().into(),
+                            ..base_style.border
+                        },
+                        ..base_style
+                    }
+                }
                 style::Button::Link | style::Button::Plain => {
                     // For text/links, just adjust the text color
                     button::Style {
@@ -1607,15 +4457,41 @@ fn button_style(theme: &Theme, class: &style::Button, status: ButtonStatus) -> b
                         ..base_style
                     }
                 }
-                _ => {
-                    // For other buttons, adjust the background
-                    if let Some(Background::Color(color)) = base_style.background {
+                style::Button::Tinted(color) => {
+                    // Nudging a faint fill barely shows, so strengthen the tint instead
+                    button::Style {
+                        background: modern_base(tint(*color, 1.5), base_style.text_color)
+                            .background,
+                        ..base_style
+                    }
+                }
+                style::Button::Secondary => {
+                    if config.outline_fill_on_hover {
+                        // Fill with the accent for a stronger affordance
                         button::Style {
-                            background: Some(Background::Color(adjust_color(color))),
+                            background: Some(Background::Color(colors.blue)),
+                            text_color: accent_label_color(colors.blue),
                             ..base_style
                         }
                     } else {
-                        base_style
+                        // Subtle accent tint behind the outline
+                        button::Style {
+                            background: Some(Background::Color(solidify(
+                                theme,
+                                colors.blue.scale_alpha(0.1),
+                                config,
+                            ))),
+                            ..base_style
+                        }
+                    }
+                }
+                _ => {
+                    // For other buttons, adjust the background
+                    button::Style {
+                        background: base_style
+                            .background
+                            .map(|background| map_background(background, adjust_color)),
+                        ..base_style
                     }
                 }
             }
@@ -1645,41 +4521,70 @@ fn button_style(theme: &Theme, class: &style::Button, status: ButtonStatus) -> b
 
             let mut pressed_style = base_style;
 
-            // Remove shadow when pressed (Modern's buttons appear to press down)
-            pressed_style.shadow = Shadow::default();
+            // Remove shadow when pressed (Modern's buttons appear to press down),
+            // unless the config asks for a flat press that keeps it
+            if !config.shadow_on_press {
+                pressed_style.shadow = Shadow::default();
+            }
 
             match class {
-                style::Button::Link | style::Button::Plain => {
-                    // For text/links, just adjust the text color more
+                _ if config.press_flash => {
+                    // Brighten instead of darkening, for a visible tap flash
+                    button_flash_style(pressed_style, PRESS_FLASH_INTENSITY)
+                }
+                style::Button::Link | style::Button::Plain if config.text_button_hover_bg => {
+                    // Deepen the hover highlight
+                    pressed_style.background =
+                        Some(Background::Color(adjust_color(colors.system_bg)));
+                    pressed_style.border.radius = config.small_corner_radius().into();
+                    pressed_style
+                }
+                style::Button::Link => {
+                    // Links deepen to a pressed link color instead of fading
+                    pressed_style.text_color = pressed_link_color(&colors);
+                    pressed_style
+                }
+                style::Button::Plain => {
+                    // For plain text, just adjust the text color more
                     pressed_style.text_color = base_style.text_color.scale_alpha(0.6);
                     pressed_style
                 }
+                style::Button::Tinted(color) => {
+                    pressed_style.background =
+                        modern_base(tint(*color, 2.0), base_style.text_color).background;
+                    pressed_style
+                }
+                style::Button::Secondary => {
+                    if config.outline_fill_on_hover {
+                        // Keep the fill from the hover state, just deeper
+                        pressed_style.background =
+                            Some(Background::Color(adjust_color(colors.blue)));
+                        pressed_style.text_color = accent_label_color(colors.blue);
+                    } else {
+                        pressed_style.background = Some(Background::Color(solidify(
+                            theme,
+                            colors.blue.scale_alpha(0.2),
+                            config,
+                        )));
+                    }
+
+                    // Thicken and deepen the outline so the press reads on the border too
+                    pressed_style.border.width = 1.5;
+                    pressed_style.border.color = adjust_color(colors.blue);
+                    pressed_style
+                }
                 _ => {
                     // For other buttons, adjust the background more
-                    if let Some(Background::Color(color)) = base_style.background {
-                        pressed_style.background = Some(Background::Color(adjust_color(color)));
-                    }
+                    pressed_style.background = base_style
+                        .background
+                        .map(|background| map_background(background, adjust_color));
                     pressed_style
                 }
             }
         }
 
-        ButtonStatus::Disabled => {
-            // For disabled state, reduce opacity
-            button::Style {
-                background: base_style.background.map(|bg| match bg {
-                    Background::Color(color) => Background::Color(color.scale_alpha(0.5)),
-                    _ => bg,
-                }),
-                text_color: base_style.text_color.scale_alpha(0.5),
-                border: Border {
-                    color: base_style.border.color.scale_alpha(0.5),
-                    ..base_style.border
-                },
-                shadow: Shadow::default(), // No shadow for disabled buttons
-                snap: true,
-            }
-        }
+        // For disabled state, reduce opacity
+        ButtonStatus::Disabled => button_disabled_style(base_style),
     }
 }
 
@@ -1690,3 +4595,525 @@ pub enum ValidationState {
     Warning,
     Error,
 }
+
+impl ValidationState {
+    /// Validation state of a length-limited value
+    ///
+    /// Valid below 90% of `max`, a warning from 90% up to `max` and an error
+    /// once `current` exceeds `max`.
+    pub fn from_length(current: usize, max: usize) -> Self {
+        if current > max {
+            Self::Error
+        } else if current * 10 >= max * 9 && current > 0 {
+            Self::Warning
+        } else {
+            Self::Valid
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The solid fill of a style, panicking on gradients or no background
+    fn fill(background: Option<Background>) -> Color {
+        match background {
+            Some(Background::Color(color)) => color,
+            other => panic!("expected a solid fill, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn danger_button_progress_fills_from_the_left() {
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            let red = get_theme_colors(&theme).red;
+            let style = |fill, status| Modern::danger_button_progress(fill)(&theme, status);
+
+            let empty = fill(style(0.0, ButtonStatus::Active).background);
+            let full = fill(style(1.0, ButtonStatus::Active).background);
+            assert_eq!(empty, red, "{theme}");
+            assert_ne!(full, red, "{theme}");
+
+            let Some(Background::Gradient(Gradient::Linear(half))) =
+                style(0.5, ButtonStatus::Active).background
+            else {
+                panic!("{theme}: expected a gradient at 0.5");
+            };
+            let stops: Vec<_> = half.stops.iter().flatten().collect();
+            assert_eq!(stops[1].offset, 0.5, "{theme}");
+            assert_eq!((stops[1].color, stops[2].color), (full, red), "{theme}");
+
+            // Hover and press shade the button like any other
+            for status in [ButtonStatus::Hovered, ButtonStatus::Pressed] {
+                let rest = fill(style(0.0, status).background);
+                let progress = fill(style(1.0, status).background);
+                assert_ne!(rest, red, "{theme} {status:?}");
+                assert_ne!(rest, progress, "{theme} {status:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn warning_and_amber_buttons_have_readable_labels() {
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            for (name, style) in [
+                (
+                    "warning",
+                    Modern::warning_button()(&theme, ButtonStatus::Active),
+                ),
+                (
+                    "amber",
+                    Modern::amber_button()(&theme, ButtonStatus::Active),
+                ),
+            ] {
+                let ratio = contrast_ratio(style.text_color, fill(style.background));
+                assert!(ratio >= CONTRAST_AA_LARGE, "{theme} {name}: {ratio}");
+            }
+        }
+    }
+
+    #[test]
+    fn selected_text_buttons_get_a_tint() {
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            for class in [style::Button::Link, style::Button::Plain] {
+                let base = Modern::button(class);
+                let selected = Modern::selected_button_style(Modern::button(class));
+
+                let resting = base(&theme, ButtonStatus::Active);
+                let active = selected(&theme, ButtonStatus::Active);
+                assert_eq!(
+                    fill(active.background),
+                    resting.text_color.scale_alpha(0.12),
+                    "{theme} {class:?}"
+                );
+                assert_eq!(active.border.radius, SMALL_CORNER_RADIUS.into());
+                assert_eq!(active.text_color, resting.text_color);
+
+                // Only the resting state is replaced
+                for status in [ButtonStatus::Hovered, ButtonStatus::Pressed] {
+                    let expected = base(&theme, status);
+                    let actual = selected(&theme, status);
+                    assert_eq!(actual.text_color, expected.text_color);
+                    assert_eq!(actual.background, expected.background);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn selected_gradient_button_adjusts_every_stop() {
+        let theme = Modern::light_theme();
+        let base = |_: &Theme, _| button::Style {
+            background: Some(Background::Gradient(
+                Linear::new(0.0)
+                    .add_stop(0.0, Color::from_rgb(0.5, 0.6, 0.7))
+                    .add_stop(1.0, Color::from_rgb(0.3, 0.4, 0.5))
+                    .into(),
+            )),
+            ..button::Style::default()
+        };
+
+        let selected = Modern::selected_button_style(base)(&theme, ButtonStatus::Active);
+        let Some(Background::Gradient(Gradient::Linear(linear))) = selected.background else {
+            panic!("expected a gradient, got {:?}", selected.background);
+        };
+        let stops: Vec<Color> = linear
+            .stops
+            .iter()
+            .flatten()
+            .map(|stop| stop.color)
+            .collect();
+
+        assert_eq!(stops.len(), 2);
+        assert!(stops[0].r < 0.5 && stops[1].r < 0.3, "{stops:?}");
+    }
+
+    #[test]
+    fn dynamic_card_lifts_from_card_to_floating_shadow() {
+        let theme = Modern::light_theme();
+        let shadow = |elevation| Modern::dynamic_card(elevation)(&theme).shadow;
+
+        assert_eq!(shadow(0.0), Modern::card_container()(&theme).shadow);
+        assert_eq!(shadow(1.0).blur_radius, FLOATING_SHADOW.blur_radius);
+        assert_eq!(shadow(1.0).offset, FLOATING_SHADOW.offset);
+        assert_eq!(shadow(2.0), shadow(1.0));
+
+        let half = shadow(0.5).blur_radius;
+        assert!(half > CARD_SHADOW.blur_radius && half < FLOATING_SHADOW.blur_radius);
+    }
+
+    #[test]
+    fn auto_dark_themes_read_as_dark() {
+        for variant in ModernVariant::all() {
+            let dark = Modern::auto_dark(&variant.theme());
+            let colors = get_theme_colors(&dark);
+            let palette = dark.palette();
+
+            assert!(is_dark_mode(&dark) && !is_sepia(&dark), "{variant}: {dark}");
+            assert!(
+                relative_luminance(palette.background) < relative_luminance(palette.text),
+                "{variant}: light palette on {dark}"
+            );
+            for background in [colors.background, palette.background] {
+                let ratio = contrast_ratio(colors.text, background);
+                assert!(ratio >= CONTRAST_AA, "{variant}: {ratio}");
+            }
+        }
+    }
+
+    #[test]
+    fn sepia_sidebar_uses_the_paper_shade() {
+        let sepia = Modern::sepia_theme();
+        let colors = get_theme_colors(&sepia);
+        let style = Modern::sidebar_container()(&sepia);
+
+        assert_eq!(fill(style.background), colors.secondary_background);
+    }
+
+    #[test]
+    fn provider_styles_honor_snap() {
+        let theme = Modern::dark_theme();
+        let provider = Modern::with_config(ModernConfig {
+            snap: false,
+            ..ModernConfig::default()
+        });
+
+        let containers = [
+            provider.card_container()(&theme),
+            provider.separated_container()(&theme),
+            provider.bordered_container(Color::BLACK, 1.0, 4.0)(&theme),
+            provider.accent_container()(&theme),
+            provider.accent_bar_container(TintedButtonColor::Blue)(&theme),
+            provider.accent_bar(TintedButtonColor::Blue)(&theme),
+            provider.drop_zone_container(true)(&theme),
+            provider.image_scrim_container(GradientDirection::Bottom)(&theme),
+            provider.selected_row_container()(&theme),
+            provider.keycap_container()(&theme),
+            provider.toolbar_container()(&theme),
+            provider.filled_container(Color::WHITE)(&theme),
+            provider.shadowed_container(Color::WHITE, ShadowDirection::Up, 1.0)(&theme),
+            provider.focus_ring_container()(&theme),
+            provider.split_handle_container(true, false)(&theme),
+            provider.danger_tooltip_container()(&theme),
+            provider.warning_tooltip_container()(&theme),
+        ];
+        for (index, style) in containers.iter().enumerate() {
+            assert!(!style.snap, "container #{index}");
+        }
+
+        for status in [ButtonStatus::Active, ButtonStatus::Disabled] {
+            assert!(!provider.primary_button()(&theme, status).snap);
+            assert!(!provider.secondary_button()(&theme, status).snap);
+            assert!(!provider.link_button()(&theme, status).snap);
+            assert!(!provider.ghost_button()(&theme, status).snap);
+            assert!(!provider.teal_button()(&theme, status).snap);
+            assert!(!provider.amber_button()(&theme, status).snap);
+            assert!(!provider.tab_button(true)(&theme, status).snap);
+            assert!(!provider.sidebar_item_button(true)(&theme, status).snap);
+        }
+        assert!(!provider.dynamic_card(0.5)(&theme).snap);
+
+        // The default config keeps snapping on
+        assert!(Modern::filled_container(Color::WHITE)(&theme).snap);
+        assert!(Modern::primary_button()(&theme, ButtonStatus::Active).snap);
+    }
+
+    #[test]
+    fn focused_inputs_dim_the_placeholder() {
+        let focused = TextInputStatus::Focused { is_hovered: false };
+
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            let expected = get_theme_colors(&theme)
+                .placeholder
+                .scale_alpha(FOCUSED_PLACEHOLDER_ALPHA);
+
+            for (name, style) in [
+                ("text_input", Modern::text_input()(&theme, focused)),
+                ("search_input", Modern::search_input()(&theme, focused)),
+                (
+                    "inline_text_input",
+                    Modern::inline_text_input()(&theme, focused),
+                ),
+            ] {
+                assert_eq!(style.placeholder, expected, "{theme} {name}");
+            }
+
+            // The resting placeholder keeps its full strength
+            let active = Modern::text_input()(&theme, TextInputStatus::Active);
+            assert_eq!(active.placeholder, get_theme_colors(&theme).placeholder);
+        }
+    }
+
+    #[test]
+    fn danger_and_warning_tooltips_meet_aa() {
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            for (name, style) in [
+                ("danger", Modern::danger_tooltip_container()(&theme)),
+                ("warning", Modern::warning_tooltip_container()(&theme)),
+            ] {
+                let text = style.text_color.expect("tooltip sets a text color");
+                let ratio = contrast_ratio(text, fill(style.background));
+                assert!(ratio >= CONTRAST_AA, "{theme} {name}: {ratio}");
+            }
+        }
+    }
+
+    #[test]
+    fn pressed_link_deepens_visibly() {
+        for variant in ModernVariant::all() {
+            let theme = variant.theme();
+            let active = Modern::link_button()(&theme, ButtonStatus::Active).text_color;
+            let pressed = Modern::link_button()(&theme, ButtonStatus::Pressed).text_color;
+
+            // A real color change rather than a fade
+            let ratio = contrast_ratio(active, pressed);
+            assert!(ratio >= PRESSED_LINK_CONTRAST, "{variant}: {ratio}");
+            assert_eq!(pressed.a, active.a, "{variant}");
+        }
+    }
+
+    #[test]
+    fn light_checkbox_fills_get_a_dark_check() {
+        let checked = checkbox::Status::Active { is_checked: true };
+        let yellow = Color::from_rgb(1.0, 0.9, 0.3);
+
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            let style = Modern::colored_checkbox(yellow)(&theme, checked);
+            assert_eq!(style.icon_color, on_color(yellow), "{theme}");
+            assert!(relative_luminance(style.icon_color) < relative_luminance(yellow));
+            assert!(contrast_ratio(style.icon_color, yellow) >= 3.0, "{theme}");
+
+            // Darker fills keep the usual white check
+            let blue = get_theme_colors(&theme).blue;
+            let style = Modern::colored_checkbox(blue)(&theme, checked);
+            assert_eq!(style.icon_color, Color::WHITE, "{theme}");
+        }
+    }
+
+    #[test]
+    fn tinted_button_strengthens_alpha_on_hover_and_press() {
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            let style = |status| Modern::tinted_button(TintedButtonColor::Blue)(&theme, status);
+
+            for (status, alpha) in [
+                (ButtonStatus::Active, 0.2),
+                (ButtonStatus::Hovered, 0.3),
+                (ButtonStatus::Pressed, 0.4),
+            ] {
+                let fill = fill(style(status).background);
+                assert!(
+                    (fill.a - alpha).abs() < 1e-5,
+                    "{theme} {status:?}: {}",
+                    fill.a
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn translucent_fills_change_alpha_not_rgb() {
+        let translucent = Color::from_rgba(0.2, 0.4, 0.6, 0.2);
+        let base_style = button::Style {
+            background: Some(Background::Color(translucent)),
+            ..button::Style::default()
+        };
+
+        for is_dark in [false, true] {
+            let hovered = fill(button_hover_style(base_style, is_dark).background);
+            let pressed = fill(
+                button_pressed_style(base_style, is_dark, &ModernConfig::default()).background,
+            );
+
+            assert_eq!(Color { a: 0.2, ..hovered }, translucent);
+            assert_eq!(Color { a: 0.2, ..pressed }, translucent);
+            assert!((hovered.a - 0.3).abs() < 1e-5);
+            assert!((pressed.a - 0.4).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn secondary_button_outline_deepens_on_press() {
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            let colors = get_theme_colors(&theme);
+            let border = |status| Modern::secondary_button()(&theme, status).border;
+
+            let active = border(ButtonStatus::Active);
+            let hovered = border(ButtonStatus::Hovered);
+            let pressed = border(ButtonStatus::Pressed);
+
+            assert_eq!((active.width, active.color), (1.0, colors.blue), "{theme}");
+            assert_eq!(
+                (hovered.width, hovered.color),
+                (1.0, colors.blue),
+                "{theme}"
+            );
+            assert_eq!(pressed.width, 1.5, "{theme}");
+            assert_ne!(pressed.color, colors.blue, "{theme}");
+
+            // Deeper means further from the background: darker in light mode, lighter in dark
+            let luminance = relative_luminance(pressed.color);
+            if is_dark_mode(&theme) {
+                assert!(luminance > relative_luminance(colors.blue), "{theme}");
+            } else {
+                assert!(luminance < relative_luminance(colors.blue), "{theme}");
+            }
+        }
+    }
+
+    #[test]
+    fn provider_rule_and_gray_button_honor_snap() {
+        let theme = Modern::light_theme();
+
+        for snap in [true, false] {
+            let provider = Modern::with_config(ModernConfig {
+                snap,
+                ..ModernConfig::default()
+            });
+
+            assert_eq!(provider.rule()(&theme).snap, snap);
+            for status in [
+                ButtonStatus::Active,
+                ButtonStatus::Pressed,
+                ButtonStatus::Disabled,
+            ] {
+                assert_eq!(
+                    provider.gray_button()(&theme, status).snap,
+                    snap,
+                    "{status:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn escalating_danger_button_label_gains_contrast() {
+        for variant in ModernVariant::all() {
+            let theme = variant.theme();
+            let style = |status| Modern::escalating_danger_button()(&theme, status);
+            let contrast =
+                |style: button::Style| contrast_ratio(style.text_color, fill(style.background));
+
+            let active = contrast(style(ButtonStatus::Active));
+            let hovered = contrast(style(ButtonStatus::Hovered));
+            let pressed = contrast(style(ButtonStatus::Pressed));
+
+            assert!(hovered >= CONTRAST_AA_LARGE, "{variant}: hovered {hovered}");
+            assert!(pressed >= CONTRAST_AA_LARGE, "{variant}: pressed {pressed}");
+            assert!(
+                hovered >= active && pressed >= hovered,
+                "{variant}: {active} {hovered} {pressed}"
+            );
+        }
+    }
+
+    #[test]
+    fn sidebar_shadow_mirrors_for_rtl() {
+        let theme = Modern::light_theme();
+        let rtl = ModernProvider::new(ModernConfig {
+            rtl: true,
+            ..ModernConfig::default()
+        });
+
+        assert!(Modern::sidebar_container()(&theme).shadow.offset.x > 0.0);
+        assert!(rtl.sidebar_container()(&theme).shadow.offset.x < 0.0);
+    }
+
+    #[test]
+    fn focus_ring_button_layers_over_status() {
+        let theme = Modern::light_theme();
+        let colors = get_theme_colors(&theme);
+
+        for class in [style::Button::Primary, style::Button::Secondary] {
+            let plain = Modern::button(class);
+            let ring = Modern::focus_ring_button(class, true);
+            let unfocused = Modern::focus_ring_button(class, false);
+
+            let hovered = plain(&theme, ButtonStatus::Hovered);
+            let focused_hovered = ring(&theme, ButtonStatus::Hovered);
+            assert_eq!(focused_hovered.background, hovered.background, "{class}");
+            assert_eq!(focused_hovered.text_color, hovered.text_color, "{class}");
+            assert_eq!(focused_hovered.border.width, 2.0, "{class}");
+            assert_eq!(focused_hovered.border.color, colors.blue, "{class}");
+
+            for status in [
+                ButtonStatus::Active,
+                ButtonStatus::Hovered,
+                ButtonStatus::Pressed,
+                ButtonStatus::Disabled,
+            ] {
+                assert_eq!(
+                    unfocused(&theme, status),
+                    plain(&theme, status),
+                    "{class} {status:?}"
+                );
+            }
+
+            assert_eq!(
+                ring(&theme, ButtonStatus::Disabled),
+                plain(&theme, ButtonStatus::Disabled),
+                "{class}"
+            );
+        }
+    }
+
+    #[test]
+    fn categorical_colors_are_separated() {
+        let hue_distance = |a: f32, b: f32| {
+            let distance = (a - b).rem_euclid(360.0);
+            distance.min(360.0 - distance)
+        };
+
+        for theme in [Modern::light_theme(), Modern::dark_theme()] {
+            let series = Modern::categorical_colors(&theme, 48);
+            assert_eq!(series.len(), 48);
+
+            // The built-in accents are what they are; every generated color must keep its distance
+            for (index, color) in series.iter().enumerate().skip(8) {
+                let (hue, _, lightness) = to_hsl(*color);
+
+                for other in &series[..index] {
+                    let (other_hue, _, other_lightness) = to_hsl(*other);
+
+                    assert!(
+                        hue_distance(hue, other_hue) >= 20.0
+                            || (lightness - other_lightness).abs() >= 0.1,
+                        "{theme}: color {index} ({color}) is too close to {other}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn categorical_colors_keep_their_order() {
+        let theme = Modern::light_theme();
+        let many = Modern::categorical_colors(&theme, 200);
+
+        for n in [0, 1, 8, 9, 20, 48, 130] {
+            assert_eq!(Modern::categorical_colors(&theme, n), many[..n]);
+        }
+
+        for (index, color) in many.iter().enumerate() {
+            assert!(!many[..index].contains(color), "color {index} repeats");
+        }
+    }
+
+    #[test]
+    fn filled_button_labels_are_legible() {
+        for variant in ModernVariant::all() {
+            let theme = variant.theme();
+
+            for class in [
+                style::Button::Primary,
+                style::Button::Success,
+                style::Button::Danger,
+            ] {
+                let style = Modern::button(class)(&theme, ButtonStatus::Active);
+                let ratio = contrast_ratio(style.text_color, fill(style.background));
+
+                assert!(ratio >= CONTRAST_AA_LARGE, "{variant} {class:?}: {ratio}");
+            }
+        }
+    }
+}
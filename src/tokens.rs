@@ -0,0 +1,220 @@
+//! Design-token export and import for the Modern theme.
+//!
+//! This module provides the `DesignTokens` struct, a plain JSON-friendly
+//! snapshot of a theme's colors, radii and shadows that can be shared with
+//! web or native teams and read back into an iced `Theme`.
+
+use std::collections::BTreeMap;
+
+use iced::{Color, Shadow, Theme};
+use serde::{Deserialize, Serialize};
+
+use crate::colors::{get_theme_colors_with, is_dark_mode};
+use crate::config::ModernConfig;
+use crate::theme::ModernProvider;
+
+/// A design-token snapshot of a Modern theme
+///
+/// Colors are stored as `#rrggbb` (or `#rrggbbaa` when translucent) hex
+/// strings keyed by role name, radii and shadow offsets in pixels.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesignTokens {
+    /// Name of the theme, e.g. "Modern Dark"
+    pub name: String,
+    /// Whether the theme is a dark theme
+    pub dark: bool,
+    /// The iced palette of the theme (background, text, primary, ...)
+    pub palette: BTreeMap<String, String>,
+    /// Every Modern color role (blue, text, card_bg, separator, ...)
+    pub color: BTreeMap<String, String>,
+    /// Corner radii of the widget styles
+    pub radius: RadiusTokens,
+    /// Shadows of the raised container styles (card, elevated, sheet, floating)
+    pub shadow: BTreeMap<String, ShadowToken>,
+}
+
+/// Corner radius tokens, in pixels
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RadiusTokens {
+    /// Radius of buttons
+    pub default: f32,
+    /// Radius of text inputs and pick lists
+    pub small: f32,
+    /// Radius of checkboxes
+    pub tiny: f32,
+}
+
+/// A single shadow token
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowToken {
+    /// Shadow color as a hex string
+    pub color: String,
+    /// Horizontal offset, in pixels
+    pub offset_x: f32,
+    /// Vertical offset, in pixels
+    pub offset_y: f32,
+    /// Blur radius, in pixels
+    pub blur: f32,
+}
+
+impl ShadowToken {
+    fn from_shadow(shadow: Shadow) -> Self {
+        Self {
+            color: shadow.color.to_string(),
+            offset_x: shadow.offset.x,
+            offset_y: shadow.offset.y,
+            blur: shadow.blur_radius,
+        }
+    }
+}
+
+impl DesignTokens {
+    /// Capture the tokens of a theme as styled by the given config
+    pub fn from_theme(theme: &Theme, config: &ModernConfig) -> Self {
+        let palette = theme.palette();
+        let colors = get_theme_colors_with(theme, config);
+        let provider = ModernProvider::new(*config);
+
+        let hex = |entries: &[(&str, Color)]| -> BTreeMap<String, String> {
+            entries
+                .iter()
+                .map(|(role, color)| (role.to_string(), color.to_string()))
+                .collect()
+        };
+
+        let shadow = [
+            ("card", provider.card_container()(theme).shadow),
+            ("elevated", provider.elevated_container()(theme).shadow),
+            ("sheet", provider.sheet_container()(theme).shadow),
+            ("floating", provider.floating_container()(theme).shadow),
+        ]
+        .into_iter()
+        .map(|(role, shadow)| (role.to_string(), ShadowToken::from_shadow(shadow)))
+        .collect();
+
+        Self {
+            name: theme.to_string(),
+            dark: is_dark_mode(theme),
+            palette: hex(&[
+                ("background", palette.background),
+                ("text", palette.text),
+                ("primary", palette.primary),
+                ("success", palette.success),
+                ("danger", palette.danger),
+                ("warning", palette.warning),
+            ]),
+            color: hex(&colors.entries()),
+            radius: RadiusTokens {
+                default: config.corner_radius,
+                small: config.small_corner_radius(),
+                tiny: config.tiny_corner_radius(),
+            },
+            shadow,
+        }
+    }
+
+    /// Rebuild the theme and config described by these tokens
+    ///
+    /// The theme keeps the exported name so dark mode detection still works.
+    /// The primary palette color becomes the config accent unless it is the
+    /// blue the named theme uses by default. Derived color roles and shadows are recomputed
+    /// from the palette, so only `palette`, `name` and `radius` are read.
+    pub fn to_theme(&self) -> Result<(Theme, ModernConfig), serde_json::Error> {
+        let entry = |role: &str| -> Result<Color, serde_json::Error> {
+            let value = self.palette.get(role).ok_or_else(|| {
+                serde::de::Error::custom(format!("missing palette color `{role}`"))
+            })?;
+
+            value.parse().map_err(|error| {
+                serde::de::Error::custom(format!("invalid palette color `{role}`: {error}"))
+            })
+        };
+
+        let palette = iced::theme::Palette {
+            background: entry("background")?,
+            text: entry("text")?,
+            primary: entry("primary")?,
+            success: entry("success")?,
+            danger: entry("danger")?,
+            warning: entry("warning")?,
+        };
+
+        let theme = Theme::custom(self.name.clone(), palette);
+
+        // The palette blue the named theme would have without an accent, e.g.
+        // the accessible blue of a high-contrast theme
+        let unsoftened = ModernConfig {
+            desaturate_dark_accents: false,
+            ..ModernConfig::default()
+        };
+        let standard_blue = get_theme_colors_with(&theme, &unsoftened).blue;
+
+        let config = ModernConfig {
+            accent: (palette.primary.into_rgba8() != standard_blue.into_rgba8())
+                .then_some(palette.primary),
+            corner_radius: self.radius.default,
+            ..ModernConfig::default()
+        };
+
+        Ok((theme, config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::ModernVariant;
+    use crate::theme::Modern;
+
+    #[test]
+    fn tokens_round_trip_every_variant() {
+        for variant in ModernVariant::all() {
+            let theme = variant.theme();
+            let (imported, config) = Modern::import_tokens(&Modern::export_tokens(&theme))
+                .unwrap_or_else(|error| panic!("{variant}: {error}"));
+
+            let (expected, actual) = (theme.palette(), imported.palette());
+            for (original, read) in [
+                (expected.background, actual.background),
+                (expected.text, actual.text),
+                (expected.primary, actual.primary),
+                (expected.success, actual.success),
+                (expected.danger, actual.danger),
+                (expected.warning, actual.warning),
+            ] {
+                assert_eq!(original.into_rgba8(), read.into_rgba8(), "{variant}");
+            }
+
+            assert_eq!(imported.to_string(), theme.to_string());
+            assert_eq!(config.corner_radius, ModernConfig::default().corner_radius);
+            assert_eq!(config.accent, None, "{variant}");
+        }
+    }
+
+    #[test]
+    fn brand_accent_survives_the_round_trip() {
+        let accent = Color::from_rgb8(0xE6, 0x39, 0x46);
+        let json = Modern::export_tokens(&Modern::themed(false, accent));
+        let (_, config) = Modern::import_tokens(&json).expect("exported tokens import");
+
+        assert_eq!(
+            config.accent.map(Color::into_rgba8),
+            Some(accent.into_rgba8())
+        );
+    }
+
+    #[test]
+    fn malformed_or_missing_palette_entries_are_errors() {
+        let mut tokens = DesignTokens::from_theme(&Modern::dark_theme(), &ModernConfig::default());
+        tokens
+            .palette
+            .insert("primary".to_string(), "#zzzzzz".to_string());
+        assert!(tokens.to_theme().is_err());
+
+        tokens.palette.remove("primary");
+        assert!(tokens.to_theme().is_err());
+
+        assert!(Modern::import_tokens("{ \"name\": \"Modern Dark\" }").is_err());
+        assert!(Modern::import_tokens("not json").is_err());
+    }
+}